@@ -1,20 +1,55 @@
+use crate::rendering::Canvas;
+
+mod banner_line;
+mod blank_line;
+mod figlet_line;
 mod text_line;
 mod key_value_line;
+mod rich_text_line;
+mod scaled_line;
+mod span;
+pub mod markup;
 
 // Re-exports
+pub use banner_line::BannerLine;
+pub use blank_line::BlankLine;
+pub use figlet_line::FigletLine;
 pub use text_line::TextLine;
 pub use key_value_line::KeyValueLine;
+pub use rich_text_line::RichTextLine;
+pub use scaled_line::ScaledLine;
+pub use span::Span;
 
 /// Lines render a line of text within a banner.
 pub trait Line {
-    /// Formats the line.
+    /// Draws the line into a canvas.
+    ///
+    /// Content beyond the edge of the canvas is clipped.
     ///
     /// # Arguments
     ///
-    /// * `self` - The line to format.
-    /// * `no_color_codes` - A flag indicating whether to suppress color codes.
-    fn fmt(self: &Self, no_color_codes: bool) -> String;
+    /// * `self` - The line to draw.
+    /// * `canvas` - The canvas to draw into.
+    /// * `x` - The column of the left edge of the line.
+    /// * `y` - The row of the top of the line.
+    /// * `width` - The width of the content area the line is drawn in.
+    fn draw(self: &Self, canvas: &mut Canvas, x: usize, y: usize, width: u8);
 
-    /// Returns the width of the line when formatted.
+    /// Returns the width of the line when drawn.
     fn width(self: &Self) -> u8;
-}
\ No newline at end of file
+
+    /// Returns the number of rows the line occupies.
+    ///
+    /// Lines that span several rows (such as nested banners) override this.
+    fn height(self: &Self) -> usize {
+        1
+    }
+
+    /// Returns the line as drawn at its normal size, for lines drawn at a larger scale.
+    ///
+    /// Scaled lines can only be shown when they span whole rows of the terminal; elsewhere,
+    /// this line is drawn instead.
+    fn unscaled(self: &Self) -> Option<&dyn Line> {
+        None
+    }
+}
@@ -0,0 +1,59 @@
+use super::Line;
+use crate::rendering::{Canvas, Joins};
+use crate::Banner;
+
+/// Describes a line containing a complete banner, drawn inside the parent banner.
+///
+/// The nested banner does not span whole rows, so its scaled lines are drawn at their normal
+/// size.
+///
+/// # Arguments
+///
+/// * `banner` - The nested banner.
+pub struct BannerLine<'a> {
+    pub banner: Banner<'a>,
+}
+
+impl<'a> Line for BannerLine<'a> {
+    /// Draws the nested banner, stretched to fill the given width.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The banner line to draw.
+    /// * `canvas` - The canvas to draw into.
+    /// * `x` - The column of the left edge of the nested banner.
+    /// * `y` - The row of the top edge of the nested banner.
+    /// * `width` - The width of the parent banner's content area.
+    fn draw(self: &Self, canvas: &mut Canvas, x: usize, y: usize, width: u8) {
+        let frame = self.frame_width().min(u8::MAX as usize) as u8;
+        let inner_width = width.saturating_sub(frame).max(self.banner.inner_width_for(false));
+        let rendered = self.banner.render(inner_width, 0, Joins::default(), false);
+        canvas.blit(&self.banner.cast_shadow(rendered), x, y);
+    }
+
+    /// Returns the width of the nested banner, including its borders and shadow.
+    fn width(self: &Self) -> u8 {
+        (self.banner.inner_width_for(false) as usize + self.frame_width()).min(u8::MAX as usize) as u8
+    }
+
+    /// Returns the number of rows of the nested banner, including its borders and shadow.
+    fn height(self: &Self) -> usize {
+        self.banner.body_height() + 2 + self.banner.shadow_size().1
+    }
+}
+
+impl<'a> BannerLine<'a> {
+    /// Creates a new BannerLine.
+    ///
+    /// # Arguments
+    ///
+    /// * `banner` - The banner to nest.
+    pub fn new(banner: Banner<'a>) -> BannerLine<'a> {
+        BannerLine { banner }
+    }
+
+    /// Returns the number of columns taken by the nested banner's borders and shadow.
+    fn frame_width(self: &Self) -> usize {
+        self.banner.border_width() + self.banner.shadow_size().0
+    }
+}
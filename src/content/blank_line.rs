@@ -0,0 +1,23 @@
+use super::Line;
+use crate::rendering::Canvas;
+
+/// Describes an empty spacer line.
+pub struct BlankLine;
+
+impl Line for BlankLine {
+    /// Draws nothing; the blank line leaves its row empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The blank line to draw.
+    /// * `canvas` - The canvas to draw into.
+    /// * `x` - The column of the left edge of the line.
+    /// * `y` - The row of the line.
+    /// * `width` - The width of the content area.
+    fn draw(self: &Self, _canvas: &mut Canvas, _x: usize, _y: usize, _width: u8) {}
+
+    /// Returns the width of the line when rendered.
+    fn width(self: &Self) -> u8 {
+        0
+    }
+}
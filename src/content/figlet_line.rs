@@ -0,0 +1,62 @@
+use super::super::style::ElementStyle;
+use super::super::text::ansi;
+use super::Line;
+use crate::rendering::{Canvas, CellStyle};
+
+/// Describes a line of text rendered as large letters, spanning several rows.
+///
+/// # Arguments
+///
+/// * `rows` - The rows of the rendered letters.
+pub struct FigletLine<'a> {
+    pub rows: Vec<String>,
+    pub style: &'a ElementStyle,
+}
+
+impl<'a> Line for FigletLine<'a> {
+    /// Draws the rows of large letters, colored with the element style.
+    ///
+    /// If the style has a text effect, each column is colored by the effect so that it runs
+    /// across the letters.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The line to draw.
+    /// * `canvas` - The canvas to draw into.
+    /// * `x` - The column of the left edge of the line.
+    /// * `y` - The row of the top of the line.
+    /// * `width` - Ignored; the canvas clips the line.
+    fn draw(self: &Self, canvas: &mut Canvas, x: usize, y: usize, _width: u8) {
+        let columns = self.width() as usize;
+        for (row, text) in self.rows.iter().enumerate() {
+            let mut column = 0;
+            for c in text.chars() {
+                let color = self.style.effect.as_ref().and_then(|effect| effect.color_at(column, columns, self.style.effect_phase));
+                let style = CellStyle::fg(color.unwrap_or(self.style.content_color));
+                column += canvas.put_str(x + column, y + row, &c.to_string(), style);
+            }
+        }
+    }
+
+    /// Returns the width of the widest row.
+    fn width(self: &Self) -> u8 {
+        self.rows.iter().map(|row| ansi::visible_width(row)).max().unwrap_or(0).min(u8::MAX as usize) as u8
+    }
+
+    /// Returns the number of rows the letters span.
+    fn height(self: &Self) -> usize {
+        self.rows.len()
+    }
+}
+
+impl<'a> FigletLine<'a> {
+    /// Creates a new FigletLine.
+    ///
+    /// # Arguments
+    ///
+    /// * `rows` - The rows of the rendered letters.
+    /// * `style` - The element style of the line.
+    pub fn new(rows: Vec<String>, style: &'a ElementStyle) -> FigletLine<'a> {
+        FigletLine { rows, style }
+    }
+}
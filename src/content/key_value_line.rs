@@ -1,6 +1,7 @@
 use super::super::style::{ElementStyle};
+use super::super::text::ansi;
 use super::Line;
-use colored::Colorize;
+use crate::rendering::{Canvas, CellStyle};
 
 /// Describes a line of text containing a key and value pair.
 ///
@@ -8,32 +9,34 @@ use colored::Colorize;
 ///
 /// * `key` - The key name.
 /// * `value` - The value as text.
+/// * `key_style` - The element style applied to the key.
+/// * `value_style` - The element style applied to the value.
 pub struct KeyValueLine<'a> {
-    pub key: &'a str,
-    pub value: &'a str,
-    pub style: &'a ElementStyle,
+    pub key: String,
+    pub value: String,
+    pub key_style: &'a ElementStyle,
+    pub value_style: &'a ElementStyle,
 }
 
 impl<'a> Line for KeyValueLine<'a> {
-    /// Formats the key value line.
+    /// Draws the key value line, coloring the key and value with their element styles.
     ///
     /// # Arguments
     ///
-    /// * `self` - The text line to format.
-    /// * `no_color_codes` - A flag indicating whether to use color codes.
-    fn fmt(self: &Self, no_color_codes: bool) -> String {
-        let result = format!("{}: {}", self.key, self.value);
-
-        if no_color_codes {
-            result
-        } else {
-            result.color(self.style.content_color.to_string()).to_string()
-        }
+    /// * `self` - The key value line to draw.
+    /// * `canvas` - The canvas to draw into.
+    /// * `x` - The column of the left edge of the line.
+    /// * `y` - The row of the line.
+    /// * `width` - Ignored; the canvas clips the line.
+    fn draw(self: &Self, canvas: &mut Canvas, x: usize, y: usize, _width: u8) {
+        let key = format!("{}: ", self.key);
+        let x = x + canvas.put_ansi(x, y, &key, CellStyle::fg(self.key_style.content_color));
+        canvas.put_ansi(x, y, &self.value, CellStyle::fg(self.value_style.content_color));
     }
 
     /// Returns the width of the line when rendered.
     fn width(self: &Self) -> u8 {
-        (self.key.chars().count() + 2 + self.value.chars().count()) as u8
+        (ansi::visible_width(&self.key) + 2 + ansi::visible_width(&self.value)).min(u8::MAX as usize) as u8
     }
 }
 
@@ -45,7 +48,44 @@ impl<'a> KeyValueLine<'a> {
     /// * `key` - The key name.
     /// * `value` - The value as text.
     /// * `style` - The element style to apply when formatting this line.
-    pub fn new(key: &'a str, value: &'a str, style: &'a ElementStyle) -> KeyValueLine<'a> {
-        KeyValueLine { key, value, style }
+    pub fn new(key: &str, value: &str, style: &'a ElementStyle) -> KeyValueLine<'a> {
+        KeyValueLine::with_styles(key, value, style, style)
     }
-}
\ No newline at end of file
+
+    /// Creates a new KeyValueLine with separate styles for the key and the value.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key name.
+    /// * `value` - The value as text.
+    /// * `key_style` - The element style to apply to the key.
+    /// * `value_style` - The element style to apply to the value.
+    pub fn with_styles(
+        key: &str,
+        value: &str,
+        key_style: &'a ElementStyle,
+        value_style: &'a ElementStyle,
+    ) -> KeyValueLine<'a> {
+        KeyValueLine {
+            key: key.to_string(),
+            value: value.to_string(),
+            key_style,
+            value_style,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verifies that the width is capped rather than wrapped for long values.
+    #[test]
+    fn test_width_long_value() {
+        let style = ElementStyle::new();
+        let value = "v".repeat(300);
+
+        assert_eq!(u8::MAX, KeyValueLine::new("key", &value, &style).width());
+        assert_eq!(8, KeyValueLine::new("key", "val", &style).width());
+    }
+}
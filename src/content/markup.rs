@@ -0,0 +1,89 @@
+use super::super::style::Color;
+use super::Span;
+
+/// Parses inline markup into styled spans.
+///
+/// Supported markup:
+///
+/// * `**text**` - Renders the enclosed text in bold.
+/// * `{color}text{/}` - Renders the enclosed text in the named color (e.g. `{green}`).
+///
+/// Braces that do not form a known tag are kept as literal text.
+///
+/// # Arguments
+///
+/// * `markup` - The text to parse.
+pub fn parse(markup: &str) -> Vec<Span> {
+    let mut spans: Vec<Span> = Vec::new();
+    let mut current = String::new();
+    let mut color: Option<Color> = None;
+    let mut is_bold = false;
+    let mut rest = markup;
+
+    while let Some(c) = rest.chars().next() {
+        if rest.starts_with("**") {
+            push_span(&mut spans, &mut current, color, is_bold);
+            is_bold = !is_bold;
+            rest = &rest[2..];
+            continue;
+        }
+
+        if c == '{' {
+            if let Some(end) = rest.find('}') {
+                let tag = &rest[1..end];
+                let tag_color = Color::from_name(tag);
+                if tag == "/" || tag_color.is_some() {
+                    push_span(&mut spans, &mut current, color, is_bold);
+                    color = tag_color;
+                    rest = &rest[end + 1..];
+                    continue;
+                }
+            }
+        }
+
+        current.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+    push_span(&mut spans, &mut current, color, is_bold);
+
+    spans
+}
+
+/// Moves any pending text into a new span with the given style.
+fn push_span(spans: &mut Vec<Span>, current: &mut String, color: Option<Color>, is_bold: bool) {
+    if current.is_empty() {
+        return;
+    }
+
+    spans.push(Span {
+        text: std::mem::take(current),
+        color,
+        is_bold,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verifies that bold and color tags split the text into spans.
+    #[test]
+    fn test_parse_bold_and_color() {
+        let spans = parse("Build **passed** in {green}42s{/}");
+        assert_eq!(
+            vec![
+                Span::new("Build "),
+                Span::new("passed").bold(),
+                Span::new(" in "),
+                Span::new("42s").color(Color::Green),
+            ],
+            spans
+        );
+    }
+
+    /// Verifies that unknown tags are treated as literal text.
+    #[test]
+    fn test_parse_unknown_tag_is_literal() {
+        assert_eq!(vec![Span::new("{x} and {")], parse("{x} and {"));
+    }
+}
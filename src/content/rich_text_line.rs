@@ -0,0 +1,66 @@
+use super::super::style::ElementStyle;
+use super::{Line, Span};
+use crate::rendering::{Canvas, CellStyle};
+
+/// Describes a line of text made up of individually styled spans.
+///
+/// # Arguments
+///
+/// * `spans` - The spans making up the line.
+/// * `style` - The element style used by spans that do not set their own color.
+pub struct RichTextLine<'a> {
+    pub spans: Vec<Span>,
+    pub style: &'a ElementStyle,
+}
+
+impl<'a> Line for RichTextLine<'a> {
+    /// Draws the spans of the rich text line one after another.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The rich text line to draw.
+    /// * `canvas` - The canvas to draw into.
+    /// * `x` - The column of the left edge of the line.
+    /// * `y` - The row of the line.
+    /// * `width` - Ignored; the canvas clips the line.
+    fn draw(self: &Self, canvas: &mut Canvas, x: usize, y: usize, _width: u8) {
+        let mut x = x;
+        for span in self.spans.iter() {
+            let mut style = CellStyle::fg(span.color.unwrap_or(self.style.content_color));
+            style.attributes.bold = span.is_bold;
+            x += canvas.put_ansi(x, y, &span.text, style);
+        }
+    }
+
+    /// Returns the width of the line when rendered.
+    fn width(self: &Self) -> u8 {
+        self.spans.iter().map(|span| span.width()).sum::<usize>().min(u8::MAX as usize) as u8
+    }
+}
+
+impl<'a> RichTextLine<'a> {
+    /// Creates a new RichTextLine.
+    ///
+    /// # Arguments
+    ///
+    /// * `spans` - The spans making up the line.
+    /// * `style` - The element style used by spans that do not set their own color.
+    pub fn new(spans: Vec<Span>, style: &'a ElementStyle) -> RichTextLine<'a> {
+        RichTextLine { spans, style }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verifies that the width is capped rather than wrapped for long spans.
+    #[test]
+    fn test_width_long_spans() {
+        let style = ElementStyle::new();
+        let text = "x".repeat(200);
+        let line = RichTextLine::new(vec![Span::new(&text), Span::new(&text)], &style);
+
+        assert_eq!(u8::MAX, line.width());
+    }
+}
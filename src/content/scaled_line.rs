@@ -0,0 +1,80 @@
+use super::{Line, TextLine};
+use crate::rendering::{Canvas, LineSize};
+use crate::style::LineScale;
+
+/// Describes a line of text drawn at double width, or double width and height, using the DEC
+/// line size sequences.
+///
+/// The sequences apply to a whole row of the terminal, so the banner's borders on the row are
+/// drawn at double width too. The line is wide enough that the text and both borders fit
+/// within half of the row. Where the banner does not span whole rows, such as within a layout,
+/// the text is drawn at its normal size instead.
+///
+/// # Arguments
+///
+/// * `line` - The text, drawn at its normal size within the canvas.
+/// * `scale` - The size the text is drawn at.
+/// * `frame` - The number of columns taken by the borders of the banner.
+pub struct ScaledLine<'a> {
+    pub line: TextLine<'a>,
+    pub scale: LineScale,
+    pub frame: u8,
+}
+
+impl<'a> Line for ScaledLine<'a> {
+    /// Draws the text, marking its rows with their line size.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The line to draw.
+    /// * `canvas` - The canvas to draw into.
+    /// * `x` - The column of the left edge of the line.
+    /// * `y` - The row of the top of the line.
+    /// * `width` - The width of the content area.
+    fn draw(self: &Self, canvas: &mut Canvas, x: usize, y: usize, width: u8) {
+        self.line.draw(canvas, x, y, width);
+        match self.scale {
+            LineScale::Normal => {}
+            LineScale::DoubleWidth => canvas.set_line_size(y, LineSize::DoubleWidth, 0),
+            LineScale::DoubleHeight => {
+                canvas.set_line_size(y, LineSize::DoubleHeightTop, 0);
+                canvas.set_line_size(y + 1, LineSize::DoubleHeightBottom, 0);
+            }
+        }
+    }
+
+    /// Returns the width of the content area needed to show the text at double width, with
+    /// the borders, within half of the row.
+    fn width(self: &Self) -> u8 {
+        match self.scale {
+            LineScale::Normal => self.line.width(),
+            _ => self.line.width().saturating_mul(2).saturating_add(self.frame),
+        }
+    }
+
+    /// Returns the number of rows the text spans.
+    fn height(self: &Self) -> usize {
+        match self.scale {
+            LineScale::DoubleHeight => 2,
+            _ => 1,
+        }
+    }
+
+    /// Returns the text at its normal size.
+    fn unscaled(self: &Self) -> Option<&dyn Line> {
+        Some(&self.line)
+    }
+}
+
+impl<'a> ScaledLine<'a> {
+    /// Creates a new ScaledLine.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - The text to draw.
+    /// * `scale` - The size the text is drawn at.
+    /// * `frame` - The number of columns taken by the borders of the banner.
+    pub fn new(line: TextLine<'a>, scale: LineScale, frame: u8) -> ScaledLine<'a> {
+        ScaledLine { line, scale, frame }
+    }
+}
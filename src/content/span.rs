@@ -0,0 +1,53 @@
+use super::super::style::Color;
+use super::super::text::ansi;
+
+/// Describes a run of text within a line that shares one style.
+///
+/// Spans without a color inherit the content color of the line they belong to.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Span {
+    pub text: String,
+    pub color: Option<Color>,
+    pub is_bold: bool,
+}
+
+impl Span {
+    /// Creates a new unstyled span.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text content of the span.
+    pub fn new(text: &str) -> Span {
+        Span {
+            text: text.to_string(),
+            color: None,
+            is_bold: false,
+        }
+    }
+
+    /// Sets the color of the span.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The span to color.
+    /// * `color` - The color to apply to the span text.
+    pub fn color(mut self, color: Color) -> Span {
+        self.color = Some(color);
+        self
+    }
+
+    /// Marks the span as bold.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The span to embolden.
+    pub fn bold(mut self) -> Span {
+        self.is_bold = true;
+        self
+    }
+
+    /// Returns the width of the span when rendered.
+    pub fn width(self: &Self) -> usize {
+        ansi::visible_width(&self.text)
+    }
+}
@@ -1,6 +1,8 @@
 use super::super::style::{ElementStyle};
+use super::super::text::ansi;
 use super::Line;
-use colored::Colorize;
+use crate::rendering::{Canvas, CellStyle};
+use unicode_segmentation::UnicodeSegmentation;
 
 /// Describes a line of text.
 ///
@@ -8,28 +10,45 @@ use colored::Colorize;
 ///
 /// * `text` - The text content.
 pub struct TextLine<'a> {
-    pub text: &'a str,
+    pub text: String,
     pub style: &'a ElementStyle,
 }
 
 impl<'a> Line for TextLine<'a> {
-    /// Formats the text line.
+    /// Draws the text line, colored with its element style.
+    ///
+    /// The style's text transform and letter spacing are applied first. If the style has a text
+    /// effect, each grapheme is colored by the effect and any color codes in the text are ignored.
     ///
     /// # Arguments
     ///
-    /// * `self` - The text line to format.
-    /// * `no_color_codes` - A flag indicating whether to suppress color codes.
-    fn fmt(self: &Self, no_color_codes: bool) -> String {
-        if no_color_codes {
-            self.text.to_string()
-        } else {
-            self.text.color(self.style.content_color.to_string()).to_string()
+    /// * `self` - The text line to draw.
+    /// * `canvas` - The canvas to draw into.
+    /// * `x` - The column of the left edge of the line.
+    /// * `y` - The row of the line.
+    /// * `width` - Ignored; the canvas clips the line.
+    fn draw(self: &Self, canvas: &mut Canvas, x: usize, y: usize, _width: u8) {
+        let text = self.style.apply_transforms(&self.text);
+        match &self.style.effect {
+            Some(effect) => {
+                let text = ansi::strip(&text);
+                let graphemes: Vec<&str> = text.graphemes(true).collect();
+                let mut column = x;
+                for (index, grapheme) in graphemes.iter().enumerate() {
+                    let color = effect.color_at(index, graphemes.len(), self.style.effect_phase);
+                    let style = color.map_or_else(|| CellStyle::fg(self.style.content_color), CellStyle::fg);
+                    column += canvas.put_str(column, y, grapheme, style);
+                }
+            }
+            None => {
+                canvas.put_ansi(x, y, &text, CellStyle::fg(self.style.content_color));
+            }
         }
     }
 
-    /// Returns the width of the line when rendered.
+    /// Returns the width of the line when rendered, after the style's transforms.
     fn width(self: &Self) -> u8 {
-        self.text.chars().count() as u8
+        ansi::visible_width(&self.style.apply_transforms(&self.text)).min(u8::MAX as usize) as u8
     }
 }
 
@@ -40,7 +59,60 @@ impl<'a> TextLine<'a> {
     ///
     /// * `text` - The content of the text line.
     /// * `level` - The formatting level of the new text line.
-    pub fn new(text: &'a str, style: &'a ElementStyle) -> TextLine<'a> {
-        TextLine { text, style }
+    pub fn new(text: &str, style: &'a ElementStyle) -> TextLine<'a> {
+        TextLine { text: text.to_string(), style }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::{Color, TextEffect};
+
+    /// Verifies that text effects color each grapheme, keeping combining marks with their base.
+    #[test]
+    fn test_draw_effect_graphemes() {
+        let mut style = ElementStyle::new();
+        style.effect = Some(TextEffect::Rainbow);
+        let line = TextLine::new("ae\u{301}i", &style);
+
+        let mut canvas = Canvas::new(3, 1);
+        line.draw(&mut canvas, 0, 0, 3);
+
+        assert_eq!(vec!["ae\u{301}i"], canvas.to_plain_rows());
+        assert_eq!(Some(Color::Rgb(255, 0, 0)), canvas.cell(0, 0).unwrap().style.fg);
+        assert_eq!(Some(Color::Rgb(0, 255, 0)), canvas.cell(1, 0).unwrap().style.fg);
+        assert_eq!(Some(Color::Rgb(0, 0, 255)), canvas.cell(2, 0).unwrap().style.fg);
+        assert_eq!(3, line.width());
+    }
+
+    /// Verifies that advancing the phase shifts the effect colors along the text.
+    #[test]
+    fn test_draw_effect_phase() {
+        let mut style = ElementStyle::new();
+        style.effect = Some(TextEffect::Rainbow);
+        style.effect_phase = 1.0 / 3.0;
+        let line = TextLine::new("abc", &style);
+
+        let mut canvas = Canvas::new(3, 1);
+        line.draw(&mut canvas, 0, 0, 3);
+
+        assert_eq!(Some(Color::Rgb(0, 255, 0)), canvas.cell(0, 0).unwrap().style.fg);
+        assert_eq!(Some(Color::Rgb(255, 0, 0)), canvas.cell(2, 0).unwrap().style.fg);
+    }
+
+    /// Verifies that text transforms and letter spacing are drawn and counted in the width.
+    #[test]
+    fn test_draw_transforms() {
+        let mut style = ElementStyle::new();
+        style.transform = crate::style::TextTransform::Uppercase;
+        style.letter_spacing = 1;
+        let line = TextLine::new("go", &style);
+
+        let mut canvas = Canvas::new(4, 1);
+        line.draw(&mut canvas, 0, 0, 4);
+
+        assert_eq!(vec!["G O "], canvas.to_plain_rows());
+        assert_eq!(3, line.width());
     }
 }
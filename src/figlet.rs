@@ -0,0 +1,6 @@
+mod figlet_font;
+mod font_error;
+mod smushing;
+
+pub use figlet_font::FigletFont;
+pub use font_error::FontError;
@@ -0,0 +1,284 @@
+use super::smushing::{self, KERNING, SMUSHING};
+use super::FontError;
+use std::collections::HashMap;
+use std::path::Path;
+
+const BLOCK_FONT: &str = include_str!("fonts/block.flf");
+const MINI_FONT: &str = include_str!("fonts/mini.flf");
+
+/// The characters every FIGlet font defines after its comments, in order: printable ASCII,
+/// then the optional Deutsch characters.
+const ASCII_CHARS: std::ops::RangeInclusive<u32> = 32..=126;
+const DEUTSCH_CHARS: [char; 7] = ['Ä', 'Ö', 'Ü', 'ä', 'ö', 'ü', 'ß'];
+
+/// Describes a FIGlet font, used to render text as large multi-row letters.
+#[derive(Clone, Debug)]
+pub struct FigletFont {
+    hardblank: char,
+    height: usize,
+    layout: u32,
+    chars: HashMap<char, Vec<Vec<char>>>,
+}
+
+impl FigletFont {
+    /// Returns the bundled five row font drawn with full blocks.
+    pub fn block() -> FigletFont {
+        FigletFont::parse(BLOCK_FONT).expect("the bundled block font is valid")
+    }
+
+    /// Returns the bundled three row font drawn with half blocks.
+    pub fn mini() -> FigletFont {
+        FigletFont::parse(MINI_FONT).expect("the bundled mini font is valid")
+    }
+
+    /// Loads a font from a standard FIGlet `.flf` file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the font file.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<FigletFont, FontError> {
+        FigletFont::parse(&std::fs::read_to_string(path)?)
+    }
+
+    /// Parses a font from the contents of a FIGlet `.flf` file.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The contents of the font file.
+    pub fn parse(source: &str) -> Result<FigletFont, FontError> {
+        let mut lines = source.lines();
+        let header = lines.next().ok_or(FontError::InvalidHeader)?;
+        let hardblank = header.strip_prefix("flf2a").and_then(|rest| rest.chars().next()).ok_or(FontError::InvalidHeader)?;
+        let numbers: Vec<i32> = header
+            .split_whitespace()
+            .skip(1)
+            .map(|number| number.parse().map_err(|_| FontError::InvalidHeader))
+            .collect::<Result<_, _>>()?;
+        if numbers.len() < 5 || numbers[0] < 1 || numbers[4] < 0 {
+            return Err(FontError::InvalidHeader);
+        }
+        let height = numbers[0] as usize;
+        let layout = numbers.get(6).map_or_else(|| smushing::from_old_layout(numbers[3]), |layout| *layout as u32);
+
+        let mut lines = lines.skip(numbers[4] as usize);
+        let mut chars = HashMap::new();
+        for code in ASCII_CHARS {
+            let glyph = read_glyph(&mut lines, height).ok_or(FontError::Truncated)?;
+            chars.insert(std::char::from_u32(code).unwrap_or(' '), glyph);
+        }
+        for c in DEUTSCH_CHARS.iter() {
+            match read_glyph(&mut lines, height) {
+                Some(glyph) => chars.insert(*c, glyph),
+                None => break,
+            };
+        }
+
+        // Any further characters are each introduced by a line starting with their code
+        while let Some(tag) = lines.next() {
+            let glyph = read_glyph(&mut lines, height).ok_or(FontError::Truncated)?;
+            if let Some(c) = tag.split_whitespace().next().and_then(parse_code) {
+                chars.insert(c, glyph);
+            }
+        }
+
+        Ok(FigletFont { hardblank, height, layout, chars })
+    }
+
+    /// Returns the number of rows each line of text is rendered as.
+    pub fn height(self: &Self) -> usize {
+        self.height
+    }
+
+    /// Renders a line of text as rows of large letters.
+    ///
+    /// Letters are kerned or smushed together as the font's layout describes. Characters the
+    /// font does not define are skipped, and trailing spaces are trimmed from each row.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The font to render with.
+    /// * `text` - The text to render, without line breaks.
+    pub fn render(self: &Self, text: &str) -> Vec<String> {
+        let mut rows: Vec<Vec<char>> = vec![Vec::new(); self.height];
+        let mut previous_width = 0;
+
+        for glyph in text.chars().filter_map(|c| self.chars.get(&c)) {
+            let width = glyph.iter().map(Vec::len).max().unwrap_or(0);
+            let layout = if previous_width < 2 || width < 2 { self.layout & !SMUSHING } else { self.layout };
+            let overlap = self.overlap(&rows, glyph, width, layout);
+
+            for (row, glyph_row) in rows.iter_mut().zip(glyph.iter()) {
+                let start = row.len() - overlap;
+                for (k, c) in glyph_row.iter().chain(std::iter::repeat(&' ')).take(width).enumerate() {
+                    if k < overlap {
+                        row[start + k] = smushing::smush(row[start + k], *c, layout, self.hardblank).unwrap_or(*c);
+                    } else {
+                        row.push(*c);
+                    }
+                }
+            }
+            previous_width = width;
+        }
+
+        rows.iter()
+            .map(|row| {
+                let row: String = row.iter().map(|c| if *c == self.hardblank { ' ' } else { *c }).collect();
+                row.trim_end().to_string()
+            })
+            .collect()
+    }
+
+    /// Returns the number of columns a glyph can overlap the rendered rows by.
+    ///
+    /// Kerning moves the glyph left until it touches the rows, and smushing moves it one
+    /// column further where the touching characters can be smushed.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The font being rendered.
+    /// * `rows` - The rows rendered so far, all of the same length.
+    /// * `glyph` - The glyph being added.
+    /// * `width` - The width of the glyph.
+    /// * `layout` - The layout to join the glyph with.
+    fn overlap(self: &Self, rows: &[Vec<char>], glyph: &[Vec<char>], width: usize, layout: u32) -> usize {
+        if layout & (KERNING | SMUSHING) == 0 {
+            return 0;
+        }
+
+        let length = rows.first().map_or(0, Vec::len);
+        let mut overlap = width.min(length);
+        for (row, glyph_row) in rows.iter().zip(glyph.iter()) {
+            // The last visible character of the row and the first of the glyph
+            let last = row.iter().rposition(|c| *c != ' ');
+            let first = glyph_row.iter().position(|c| *c != ' ').unwrap_or(width);
+            let mut amount = first + length - last.map_or(0, |last| last + 1);
+            if let (Some(last), Some(c)) = (last, glyph_row.get(first)) {
+                if smushing::smush(row[last], *c, layout, self.hardblank).is_some() {
+                    amount += 1;
+                }
+            }
+            overlap = overlap.min(amount);
+        }
+
+        overlap
+    }
+}
+
+/// Reads the rows of one glyph, removing the end marks from each row.
+///
+/// # Arguments
+///
+/// * `lines` - The remaining lines of the font file.
+/// * `height` - The number of rows in each glyph.
+fn read_glyph<'a, I: Iterator<Item = &'a str>>(lines: &mut I, height: usize) -> Option<Vec<Vec<char>>> {
+    let mut glyph = Vec::with_capacity(height);
+    for _ in 0..height {
+        let line = lines.next()?.trim_end();
+        let row = match line.chars().last() {
+            Some(end_mark) => line.trim_end_matches(end_mark),
+            None => line,
+        };
+        glyph.push(row.chars().collect());
+    }
+
+    Some(glyph)
+}
+
+/// Parses the character code of a code tagged character, written in decimal, octal (`0`
+/// prefix) or hexadecimal (`0x` prefix). Returns None for negative or invalid codes.
+///
+/// # Arguments
+///
+/// * `code` - The code to parse.
+fn parse_code(code: &str) -> Option<char> {
+    let value = if let Some(hex) = code.strip_prefix("0x").or_else(|| code.strip_prefix("0X")) {
+        u32::from_str_radix(hex, 16).ok()?
+    } else if code.len() > 1 && code.starts_with('0') {
+        u32::from_str_radix(&code[1..], 8).ok()?
+    } else {
+        code.parse().ok()?
+    };
+
+    std::char::from_u32(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds a font with two rows from glyphs for the given characters, with every other
+    /// printable character left empty.
+    fn font(layout: i32, glyphs: &[(char, [&str; 2])]) -> FigletFont {
+        let mut source = format!("flf2a$ 2 2 8 {} 1\ncomment\n", layout);
+        for code in ASCII_CHARS {
+            let c = std::char::from_u32(code).unwrap();
+            let rows = glyphs.iter().find(|(g, _)| *g == c).map_or(["", ""], |(_, rows)| *rows);
+            source.push_str(&format!("{}@\n{}@@\n", rows[0], rows[1]));
+        }
+        FigletFont::parse(&source).unwrap()
+    }
+
+    /// Verifies that the header and characters of a font are read.
+    #[test]
+    fn test_parse() {
+        let font = font(-1, &[('A', ["/\\", "||"])]);
+
+        assert_eq!(2, font.height());
+        assert_eq!(vec!["/\\/\\", "||||"], font.render("AA"));
+    }
+
+    /// Verifies that invalid and incomplete fonts are rejected.
+    #[test]
+    fn test_parse_errors() {
+        assert!(matches!(FigletFont::parse("flf2b$ 2 2 8 0 0"), Err(FontError::InvalidHeader)));
+        assert!(matches!(FigletFont::parse("flf2a$ 2 x 8 0 0"), Err(FontError::InvalidHeader)));
+        assert!(matches!(FigletFont::parse("flf2a$ 2 2 8 0 0\n@\n@@\n"), Err(FontError::Truncated)));
+    }
+
+    /// Verifies that kerning moves letters together until they touch, keeping hardblanks.
+    #[test]
+    fn test_render_kerning() {
+        let font = font(0, &[('L', ["|  ", "|__"]), ('T', ["___", " | "]), ('i', ["$o", "$|"])]);
+
+        assert_eq!(vec!["| ___", "|__|"], font.render("LT"));
+        assert_eq!(vec![" o o", " | |"], font.render("ii"));
+    }
+
+    /// Verifies that smushing overlaps touching letters by one more column.
+    #[test]
+    fn test_render_smushing() {
+        let font = font(15, &[('A', ["/\\ ", "|| "]), ('B', ["|) ", "|) "]), ('C', [" _ ", "(_ "])]);
+
+        assert_eq!(vec!["/\\)", "||)"], font.render("AB"));
+        assert_eq!(vec!["/\\_", "|(_"], font.render("AC"));
+    }
+
+    /// Verifies that code tagged characters are read.
+    #[test]
+    fn test_parse_code_tagged() {
+        let mut source = String::from("flf2a$ 1 1 4 0 0\n");
+        for _ in ASCII_CHARS.chain(0..7) {
+            source.push_str("@@\n");
+        }
+        source.push_str("0x2192 RIGHTWARDS ARROW\n->@@\n");
+        let font = FigletFont::parse(&source).unwrap();
+
+        assert_eq!(vec!["->"], font.render("→"));
+        assert_eq!(Some('A'), parse_code("0101"));
+        assert_eq!(None, parse_code("-1"));
+    }
+
+    /// Verifies that the bundled fonts load and render every printable character.
+    #[test]
+    fn test_bundled_fonts() {
+        let block = FigletFont::block();
+        let mini = FigletFont::mini();
+
+        assert_eq!(5, block.height());
+        assert_eq!(3, mini.height());
+        assert_eq!(vec!["█ █ ███", "█ █  █", "███  █", "█ █  █", "█ █ ███"], block.render("Hi"));
+        assert_eq!(vec!["█ █ ▀█▀", "█▀█  █", "▀ ▀ ▀▀▀"], mini.render("Hi"));
+        let all: String = ASCII_CHARS.filter_map(std::char::from_u32).collect();
+        assert!(mini.render(&all).iter().all(|row| !row.is_empty()));
+    }
+}
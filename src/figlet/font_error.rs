@@ -0,0 +1,38 @@
+use std::fmt;
+use std::io;
+
+/// Describes why a FIGlet font could not be loaded.
+#[derive(Debug)]
+pub enum FontError {
+    /// The font file could not be read.
+    Io(io::Error),
+    /// The first line is not a valid `flf2a` header.
+    InvalidHeader,
+    /// The font ends before all of the required characters are defined.
+    Truncated,
+}
+
+impl fmt::Display for FontError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FontError::Io(error) => write!(f, "could not read font: {}", error),
+            FontError::InvalidHeader => write!(f, "invalid FIGlet font header"),
+            FontError::Truncated => write!(f, "FIGlet font is missing required characters"),
+        }
+    }
+}
+
+impl std::error::Error for FontError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FontError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for FontError {
+    fn from(error: io::Error) -> Self {
+        FontError::Io(error)
+    }
+}
@@ -0,0 +1,143 @@
+/// Joins characters by moving them together until they touch (horizontal fitting).
+pub const KERNING: u32 = 64;
+/// Joins characters by overlapping them by one column, following the smushing rules.
+pub const SMUSHING: u32 = 128;
+
+/// Rule 1: two equal characters smush into one.
+const EQUAL: u32 = 1;
+/// Rule 2: an underscore is replaced by a border character (`|/\[]{}()<>`).
+const UNDERSCORE: u32 = 2;
+/// Rule 3: of two characters from different classes, the later class wins.
+const HIERARCHY: u32 = 4;
+/// Rule 4: opposing brackets, braces and parentheses smush into `|`.
+const OPPOSITE_PAIR: u32 = 8;
+/// Rule 5: `/\` becomes `|`, `\/` becomes `Y` and `><` becomes `X`.
+const BIG_X: u32 = 16;
+/// Rule 6: two hardblanks smush into one.
+const HARDBLANK: u32 = 32;
+/// The bits holding the horizontal smushing rules.
+const RULES: u32 = 63;
+
+/// The character classes of the hierarchy rule, from lowest to highest.
+const HIERARCHY_CLASSES: [&str; 6] = ["|", "/\\", "[]", "{}", "()", "<>"];
+
+/// Returns the full layout of a font from the old layout in its header, for fonts that do not
+/// state a full layout.
+///
+/// # Arguments
+///
+/// * `old_layout` - The old layout: -1 for full width, 0 for kerning or the smushing rules.
+pub fn from_old_layout(old_layout: i32) -> u32 {
+    match old_layout {
+        layout if layout < 0 => 0,
+        0 => KERNING,
+        layout => SMUSHING | (layout as u32 & RULES),
+    }
+}
+
+/// Returns the character two overlapping characters smush into, or None if they cannot be
+/// smushed.
+///
+/// Spaces always give way to the other character. Other characters are only smushed when the
+/// layout enables smushing; with no rules set, the later character wins (universal smushing).
+///
+/// # Arguments
+///
+/// * `left` - The character already in the output.
+/// * `right` - The character being added.
+/// * `layout` - The full layout of the font.
+/// * `hardblank` - The font's hardblank character.
+pub fn smush(left: char, right: char, layout: u32, hardblank: char) -> Option<char> {
+    if left == ' ' {
+        return Some(right);
+    }
+    if right == ' ' {
+        return Some(left);
+    }
+    if layout & SMUSHING == 0 {
+        return None;
+    }
+
+    if layout & RULES == 0 {
+        // Universal smushing, where visible characters win over hardblanks
+        return Some(if left == hardblank { right } else if right == hardblank { left } else { right });
+    }
+
+    if left == hardblank || right == hardblank {
+        return if layout & HARDBLANK != 0 && left == right { Some(left) } else { None };
+    }
+    if layout & EQUAL != 0 && left == right {
+        return Some(left);
+    }
+    if layout & UNDERSCORE != 0 {
+        const BORDERS: &str = "|/\\[]{}()<>";
+        if left == '_' && BORDERS.contains(right) {
+            return Some(right);
+        }
+        if right == '_' && BORDERS.contains(left) {
+            return Some(left);
+        }
+    }
+    if layout & HIERARCHY != 0 {
+        let class = |c: char| HIERARCHY_CLASSES.iter().position(|class| class.contains(c));
+        if let (Some(l), Some(r)) = (class(left), class(right)) {
+            if l != r {
+                return Some(if l > r { left } else { right });
+            }
+        }
+    }
+    if layout & OPPOSITE_PAIR != 0 {
+        let pair: String = [left, right].iter().collect();
+        if ["[]", "][", "{}", "}{", "()", ")("].contains(&pair.as_str()) {
+            return Some('|');
+        }
+    }
+    if layout & BIG_X != 0 {
+        match (left, right) {
+            ('/', '\\') => return Some('|'),
+            ('\\', '/') => return Some('Y'),
+            ('>', '<') => return Some('X'),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verifies each of the controlled smushing rules.
+    #[test]
+    fn test_smush_rules() {
+        let all = SMUSHING | RULES;
+        assert_eq!(Some('#'), smush('#', '#', all, '$'));
+        assert_eq!(Some('/'), smush('_', '/', all, '$'));
+        assert_eq!(Some('{'), smush('{', '/', all, '$'));
+        assert_eq!(Some('|'), smush('[', ']', all, '$'));
+        assert_eq!(Some('Y'), smush('\\', '/', all, '$'));
+        assert_eq!(Some('X'), smush('>', '<', all, '$'));
+        assert_eq!(Some('$'), smush('$', '$', all, '$'));
+        assert_eq!(None, smush('$', '#', all, '$'));
+        assert_eq!(None, smush('a', 'b', all, '$'));
+        assert_eq!(None, smush('#', '#', SMUSHING | HIERARCHY, '$'));
+    }
+
+    /// Verifies universal smushing and that kerning never overlaps visible characters.
+    #[test]
+    fn test_smush_universal_and_kerning() {
+        assert_eq!(Some('b'), smush('a', 'b', SMUSHING, '$'));
+        assert_eq!(Some('a'), smush('a', '$', SMUSHING, '$'));
+        assert_eq!(None, smush('a', 'b', KERNING, '$'));
+        assert_eq!(Some('a'), smush('a', ' ', KERNING, '$'));
+    }
+
+    /// Verifies converting the old layout from a font header.
+    #[test]
+    fn test_from_old_layout() {
+        assert_eq!(0, from_old_layout(-1));
+        assert_eq!(KERNING, from_old_layout(0));
+        assert_eq!(SMUSHING | 15, from_old_layout(15));
+    }
+}
@@ -0,0 +1,65 @@
+use crate::rendering::Canvas;
+
+mod banner_group;
+mod columns;
+mod grid;
+mod horizontal_alignment;
+mod overflow;
+mod overlay;
+mod stack;
+mod vertical_alignment;
+mod width_mode;
+
+pub use banner_group::BannerGroup;
+pub use columns::Columns;
+pub use grid::Grid;
+pub use horizontal_alignment::HorizontalAlignment;
+pub use overflow::Overflow;
+pub use overlay::Overlay;
+pub use stack::Stack;
+pub use vertical_alignment::VerticalAlignment;
+pub use width_mode::WidthMode;
+
+/// Joins canvases side by side, separated by a gap.
+///
+/// The result is as tall as the tallest canvas, and shorter canvases are aligned to the top.
+///
+/// # Arguments
+///
+/// * `blocks` - The canvases to join, from left to right.
+/// * `gap` - The number of blank columns between adjacent canvases.
+fn join_side_by_side(blocks: &[Canvas], gap: usize) -> Canvas {
+    let width = blocks.iter().map(|block| block.width()).sum::<usize>() + gap * blocks.len().saturating_sub(1);
+    let height = blocks.iter().map(|block| block.height()).max().unwrap_or(0);
+    let mut canvas = Canvas::new(width, height);
+
+    let mut x = 0;
+    for block in blocks.iter() {
+        canvas.blit(block, x, 0);
+        x += block.width() + gap;
+    }
+
+    canvas
+}
+
+/// Joins canvases from top to bottom, separated by a gap.
+///
+/// The result is as wide as the widest canvas, and narrower canvases are aligned to the left.
+///
+/// # Arguments
+///
+/// * `blocks` - The canvases to join, from top to bottom.
+/// * `gap` - The number of blank rows between adjacent canvases.
+fn join_top_to_bottom(blocks: &[Canvas], gap: usize) -> Canvas {
+    let width = blocks.iter().map(|block| block.width()).max().unwrap_or(0);
+    let height = blocks.iter().map(|block| block.height()).sum::<usize>() + gap * blocks.len().saturating_sub(1);
+    let mut canvas = Canvas::new(width, height);
+
+    let mut y = 0;
+    for block in blocks.iter() {
+        canvas.blit(block, 0, y);
+        y += block.height() + gap;
+    }
+
+    canvas
+}
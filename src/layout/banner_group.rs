@@ -0,0 +1,122 @@
+use crate::rendering::Joins;
+use crate::Banner;
+
+/// Renders banners at a common width so that they line up.
+///
+/// Banners are registered with the group and rendered together at the width of the widest
+/// registered banner. Optionally, when `persist_width` is set, the chosen width is remembered
+/// so that banners rendered later are never narrower than those already printed. It is off by
+/// default, so each batch is sized on its own.
+pub struct BannerGroup<'a> {
+    /// True to keep the chosen width for banners rendered later.
+    pub persist_width: bool,
+    width: u8,
+    banners: Vec<Banner<'a>>,
+}
+
+impl<'a> BannerGroup<'a> {
+    /// Creates a new, empty banner group.
+    pub fn new() -> BannerGroup<'a> {
+        BannerGroup {
+            persist_width: false,
+            width: 0,
+            banners: Vec::new(),
+        }
+    }
+
+    /// Registers a banner to be rendered with the group.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The group to register the banner with.
+    /// * `banner` - The banner to register.
+    pub fn add(&mut self, banner: Banner<'a>) {
+        self.banners.push(banner);
+    }
+
+    /// Returns the width, inside the borders, that registered banners will be rendered at.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The group to measure.
+    pub fn width(&self) -> u8 {
+        self.banners
+            .iter()
+            .map(|banner| banner.inner_width())
+            .fold(self.width, u8::max)
+    }
+
+    /// Assembles all registered banners at the group width, removing them from the group.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The group to assemble.
+    pub fn assemble(&mut self) -> String {
+        let width = self.width();
+        if self.persist_width {
+            self.width = width;
+        }
+
+        self.banners
+            .drain(..)
+            .flat_map(|banner| banner.cast_shadow(banner.render(width, 0, Joins::default(), true)).to_ansi_rows())
+            .map(|row| format!("{}\r\n", row))
+            .collect()
+    }
+}
+
+impl Default for BannerGroup<'_> {
+    fn default() -> Self {
+        BannerGroup::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Style;
+
+    /// Verifies that banners share the widest width and that the width persists between batches.
+    #[test]
+    fn test_assemble_persists_width() {
+        let mut style = Style::new();
+        style.no_color_codes = true;
+
+        let mut group = BannerGroup::new();
+        group.persist_width = true;
+        let mut narrow = Banner::new(&style);
+        narrow.width = 2;
+        narrow.add_text("a");
+        let mut wide = Banner::new(&style);
+        wide.width = 4;
+        wide.add_text("b");
+        group.add(narrow);
+        group.add(wide);
+        assert_eq!("┌────┐\r\n│a   │\r\n└────┘\r\n┌────┐\r\n│b   │\r\n└────┘\r\n", group.assemble());
+
+        let mut later = Banner::new(&style);
+        later.width = 2;
+        later.add_text("c");
+        group.add(later);
+        assert_eq!("┌────┐\r\n│c   │\r\n└────┘\r\n", group.assemble());
+    }
+
+    /// Verifies that the width is recomputed for each batch by default.
+    #[test]
+    fn test_assemble_without_persisting() {
+        let mut style = Style::new();
+        style.no_color_codes = true;
+
+        let mut group = BannerGroup::new();
+        assert!(!group.persist_width);
+        let mut wide = Banner::new(&style);
+        wide.width = 4;
+        group.add(wide);
+        group.assemble();
+
+        let mut narrow = Banner::new(&style);
+        narrow.width = 2;
+        group.add(narrow);
+        assert_eq!(2, group.width());
+    }
+}
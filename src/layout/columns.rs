@@ -0,0 +1,257 @@
+use super::join_side_by_side;
+use crate::rendering::{Canvas, Joins};
+use crate::Banner;
+
+const DEFAULT_GAP: u8 = 1;
+
+/// Places banners side by side.
+///
+/// Shorter banners are padded with blank rows so that all banners have the same height. Each
+/// banner casts its own shadow, except when edges are merged: the banners then form one box,
+/// which casts the shadow of the first banner's style. Since no banner spans whole rows of the
+/// terminal, scaled lines are drawn at their normal size.
+pub struct Columns<'a, 'b> {
+    /// The number of spaces between adjacent banners. Ignored when edges are merged.
+    pub gap: u8,
+    /// True to draw the edge between adjacent banners once, joined with junction glyphs.
+    pub merge_edges: bool,
+    banners: Vec<&'b Banner<'a>>,
+}
+
+impl<'a, 'b> Columns<'a, 'b> {
+    /// Creates a new, empty set of columns.
+    pub fn new() -> Columns<'a, 'b> {
+        Columns {
+            gap: DEFAULT_GAP,
+            merge_edges: false,
+            banners: Vec::new(),
+        }
+    }
+
+    /// Adds a banner to the right of the existing banners.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The columns to add the banner to.
+    /// * `banner` - The banner to add.
+    pub fn add(&mut self, banner: &'b Banner<'a>) {
+        self.banners.push(banner);
+    }
+
+    /// Renders the banners side by side as a list of rows, without line endings.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The columns to render.
+    pub fn rows(&self) -> Vec<String> {
+        self.canvas().to_ansi_rows()
+    }
+
+    /// Renders the banners side by side into a canvas of styled cells.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The columns to render.
+    pub fn canvas(&self) -> Canvas {
+        let height = self.banners.iter().map(|banner| banner.body_height()).max().unwrap_or(0);
+        let last = self.banners.len().saturating_sub(1);
+        let gap = if self.merge_edges { 0 } else { self.gap as usize };
+
+        let rendered: Vec<Canvas> = self
+            .banners
+            .iter()
+            .enumerate()
+            .map(|(i, banner)| {
+                let joins = Joins {
+                    left: self.merge_edges && i > 0,
+                    right: self.merge_edges && i < last,
+                    ..Joins::default()
+                };
+                let panel = banner.render(banner.inner_width_for(false), height, joins, false);
+                if self.merge_edges {
+                    panel
+                } else {
+                    banner.cast_shadow(panel)
+                }
+            })
+            .collect();
+
+        let mut joined = join_side_by_side(&rendered, gap);
+        if self.merge_edges {
+            // Join each shared edge to the lines of the banner on its right
+            let mut x = 0;
+            for panel in rendered.iter().take(last) {
+                x += panel.width();
+                for y in 0..joined.height() {
+                    joined.connect(x - 1, y);
+                }
+            }
+        }
+        match self.banners.first() {
+            Some(first) if self.merge_edges => first.cast_shadow(joined),
+            _ => joined,
+        }
+    }
+
+    /// Assembles the banners side by side.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The columns to assemble.
+    pub fn assemble(&self) -> String {
+        self.rows().iter().map(|row| format!("{}\r\n", row)).collect()
+    }
+}
+
+impl Default for Columns<'_, '_> {
+    fn default() -> Self {
+        Columns::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::{BorderGlyphs, LineScale, ShadowStyle};
+    use crate::{HeaderLevel, Style};
+
+    /// Creates a style without color codes for use in unit tests.
+    fn plain_style() -> Style {
+        let mut style = Style::new();
+        style.no_color_codes = true;
+        style
+    }
+
+    /// Verifies that banners are placed side by side with a gap and equalized heights.
+    #[test]
+    fn test_assemble_with_gap() {
+        let style = plain_style();
+        let mut config = Banner::new(&style);
+        config.width = 3;
+        config.add_text("a\nb");
+        let mut status = Banner::new(&style);
+        status.width = 2;
+        status.add_text("ok");
+
+        let mut columns = Columns::new();
+        columns.gap = 2;
+        columns.add(&config);
+        columns.add(&status);
+
+        let expected = "┌───┐  ┌──┐\r\n│a  │  │ok│\r\n│b  │  │  │\r\n└───┘  └──┘\r\n";
+        assert_eq!(expected, columns.assemble());
+    }
+
+    /// Verifies that merged banners share a single edge joined with junctions.
+    #[test]
+    fn test_assemble_merged_edges() {
+        let style = plain_style();
+        let mut left = Banner::new(&style);
+        left.width = 2;
+        left.add_text("L");
+        let mut middle = Banner::new(&style);
+        middle.width = 2;
+        middle.add_text("M");
+        let mut right = Banner::new(&style);
+        right.width = 2;
+        right.add_text("R\nR");
+
+        let mut columns = Columns::new();
+        columns.merge_edges = true;
+        columns.add(&left);
+        columns.add(&middle);
+        columns.add(&right);
+
+        let expected = "┌──┬──┬──┐\r\n│L │M │R │\r\n│  │  │R │\r\n└──┴──┴──┘\r\n";
+        assert_eq!(expected, columns.assemble());
+    }
+
+    /// Verifies that merged ASCII and double borders are joined with matching junctions.
+    #[test]
+    fn test_assemble_merged_edges_styles() {
+        let mut ascii = plain_style();
+        ascii.border.glyphs = BorderGlyphs::ascii();
+        let mut double = plain_style();
+        double.border.glyphs = BorderGlyphs::double();
+
+        for (style, expected) in [
+            (&ascii, "+--+--+\r\n|L |R |\r\n+--+--+\r\n"),
+            (&double, "╔══╦══╗\r\n║L ║R ║\r\n╚══╩══╝\r\n"),
+        ] {
+            let mut left = Banner::new(style);
+            left.width = 2;
+            left.add_text("L");
+            let mut right = Banner::new(style);
+            right.width = 2;
+            right.add_text("R");
+
+            let mut columns = Columns::new();
+            columns.merge_edges = true;
+            columns.add(&left);
+            columns.add(&right);
+            assert_eq!(expected, columns.assemble());
+        }
+    }
+
+    /// Verifies that each banner casts its own shadow, and merged banners cast one shadow.
+    #[test]
+    fn test_assemble_shadows() {
+        let mut style = plain_style();
+        style.shadow = Some(ShadowStyle::new());
+        let mut left = Banner::new(&style);
+        left.width = 2;
+        left.add_text("L");
+        let mut right = Banner::new(&style);
+        right.width = 2;
+        right.add_text("R");
+
+        let mut columns = Columns::new();
+        columns.add(&left);
+        columns.add(&right);
+        let expected = "┌──┐  ┌──┐ \r\n│L │░ │R │░\r\n└──┘░ └──┘░\r\n ░░░░  ░░░░\r\n";
+        assert_eq!(expected, columns.assemble());
+
+        columns.merge_edges = true;
+        let expected = "┌──┬──┐ \r\n│L │R │░\r\n└──┴──┘░\r\n ░░░░░░░\r\n";
+        assert_eq!(expected, columns.assemble());
+    }
+
+    /// Verifies that scaled headers are drawn at their normal size, without widening the banner.
+    #[test]
+    fn test_assemble_scaled_header() {
+        let mut style = plain_style();
+        style.h1.line_scale = LineScale::DoubleHeight;
+        let mut left = Banner::new(&style);
+        left.width = 2;
+        left.line_scale_support = Some(true);
+        left.add_header("Hi", HeaderLevel::H1);
+        let mut right = Banner::new(&style);
+        right.width = 2;
+        right.add_text("R");
+
+        let mut columns = Columns::new();
+        columns.add(&left);
+        columns.add(&right);
+        assert_eq!("┌──┐ ┌──┐\r\n│Hi│ │R │\r\n└──┘ └──┘\r\n", columns.assemble());
+    }
+
+    /// Verifies that merged banners with different border styles join the weights of both.
+    #[test]
+    fn test_assemble_merged_mixed_styles() {
+        let light = plain_style();
+        let mut heavy = plain_style();
+        heavy.border.glyphs = BorderGlyphs::heavy();
+        let mut left = Banner::new(&light);
+        left.width = 2;
+        left.add_text("L");
+        let mut right = Banner::new(&heavy);
+        right.width = 2;
+        right.add_text("R");
+
+        let mut columns = Columns::new();
+        columns.merge_edges = true;
+        columns.add(&left);
+        columns.add(&right);
+        assert_eq!("┌──┮━━┓\r\n│L │R ┃\r\n└──┶━━┛\r\n", columns.assemble());
+    }
+}
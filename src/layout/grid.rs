@@ -0,0 +1,206 @@
+use super::{join_side_by_side, join_top_to_bottom};
+use crate::rendering::{Canvas, Joins};
+use crate::terminal;
+use crate::Banner;
+
+const DEFAULT_GAP: u8 = 1;
+
+/// Lays out banners as a grid of equally sized panels.
+///
+/// Panels flow left to right into as many columns as fit within the total width, falling
+/// back to a single column when the width is too narrow. Every panel is widened to the width
+/// of the widest panel, and the panels in each row are padded to the same height. Scaled lines
+/// are drawn at their normal size within panels.
+pub struct Grid<'a, 'b> {
+    /// The total width to fit the panels within. Uses the terminal width when not set.
+    pub width: Option<u16>,
+    /// The number of spaces between adjacent panels.
+    pub gap: u8,
+    /// The number of blank rows between rows of panels.
+    pub row_gap: u8,
+    banners: Vec<&'b Banner<'a>>,
+}
+
+impl<'a, 'b> Grid<'a, 'b> {
+    /// Creates a new, empty grid.
+    pub fn new() -> Grid<'a, 'b> {
+        Grid {
+            width: None,
+            gap: DEFAULT_GAP,
+            row_gap: 0,
+            banners: Vec::new(),
+        }
+    }
+
+    /// Adds a panel to the grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The grid to add the panel to.
+    /// * `banner` - The banner to add.
+    pub fn add(&mut self, banner: &'b Banner<'a>) {
+        self.banners.push(banner);
+    }
+
+    /// Returns the number of panels placed in each row.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The grid to measure.
+    pub fn columns(&self) -> usize {
+        let total = self.width.unwrap_or_else(terminal::width) as usize;
+        let cell = self.cell_outer_width();
+        let gap = self.gap as usize;
+        ((total + gap) / (cell + gap).max(1)).max(1)
+    }
+
+    /// Renders the grid as a list of rows, without line endings.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The grid to render.
+    pub fn rows(&self) -> Vec<String> {
+        self.canvas().to_ansi_rows()
+    }
+
+    /// Renders the grid into a canvas of styled cells.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The grid to render.
+    pub fn canvas(&self) -> Canvas {
+        let width = self.cell_width();
+        let outer_width = self.cell_outer_width();
+        let rows: Vec<Canvas> = self
+            .banners
+            .chunks(self.columns())
+            .map(|panels| {
+                let height = panels.iter().map(|banner| banner.body_height()).max().unwrap_or(0);
+                let rendered: Vec<Canvas> = panels
+                    .iter()
+                    .map(|banner| {
+                        // Pad every panel to the same outer width so that the columns line up
+                        let panel = banner.cast_shadow(banner.render(width, height, Joins::default(), false));
+                        let mut cell = Canvas::new(outer_width.max(panel.width()), panel.height());
+                        cell.blit(&panel, 0, 0);
+                        cell
+                    })
+                    .collect();
+                join_side_by_side(&rendered, self.gap as usize)
+            })
+            .collect();
+
+        join_top_to_bottom(&rows, self.row_gap as usize)
+    }
+
+    /// Assembles the grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The grid to assemble.
+    pub fn assemble(&self) -> String {
+        self.rows().iter().map(|row| format!("{}\r\n", row)).collect()
+    }
+
+    /// Returns the width inside the borders shared by every panel.
+    fn cell_width(&self) -> u8 {
+        self.banners.iter().map(|banner| banner.inner_width_for(false)).max().unwrap_or(0)
+    }
+
+    /// Returns the width of every panel, including its borders and shadow.
+    fn cell_outer_width(&self) -> usize {
+        let frame = self.banners.iter().map(|banner| banner.border_width() + banner.shadow_size().0).max().unwrap_or(0);
+        self.cell_width() as usize + frame
+    }
+}
+
+impl Default for Grid<'_, '_> {
+    fn default() -> Self {
+        Grid::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::ShadowStyle;
+    use crate::Style;
+
+    /// Creates a banner with the given lines of text.
+    fn panel<'a>(style: &'a Style, text: &'a str) -> Banner<'a> {
+        let mut banner = Banner::new(style);
+        banner.width = 2;
+        banner.add_text(text);
+        banner
+    }
+
+    /// Verifies that panels flow into rows with equal widths and per-row heights.
+    #[test]
+    fn test_assemble_flows_panels() {
+        let mut style = Style::new();
+        style.no_color_codes = true;
+        let a = panel(&style, "a\na");
+        let b = panel(&style, "bbb");
+        let c = panel(&style, "c");
+
+        let mut grid = Grid::new();
+        grid.width = Some(11);
+        grid.add(&a);
+        grid.add(&b);
+        grid.add(&c);
+
+        assert_eq!(2, grid.columns());
+        let expected = "┌───┐ ┌───┐\r\n│a  │ │bbb│\r\n│a  │ │   │\r\n└───┘ └───┘\r\n┌───┐      \r\n│c  │      \r\n└───┘      \r\n";
+        assert_eq!(expected, grid.assemble());
+    }
+
+    /// Verifies that a narrow width falls back to a single column.
+    #[test]
+    fn test_columns_single_when_narrow() {
+        let style = Style::new();
+        let a = panel(&style, "aaaa");
+        let b = panel(&style, "b");
+
+        let mut grid = Grid::new();
+        grid.width = Some(4);
+        grid.add(&a);
+        grid.add(&b);
+
+        assert_eq!(1, grid.columns());
+    }
+
+    /// Verifies that an empty grid without gaps renders nothing rather than dividing by zero.
+    #[test]
+    fn test_assemble_empty_without_gap() {
+        let mut grid = Grid::new();
+        grid.width = Some(10);
+        grid.gap = 0;
+        assert_eq!(10, grid.columns());
+        assert_eq!("", grid.assemble());
+    }
+
+    /// Verifies that shadows are drawn on each panel and counted in the panel width.
+    #[test]
+    fn test_assemble_shadows() {
+        let mut style = Style::new();
+        style.no_color_codes = true;
+        style.shadow = Some(ShadowStyle::new());
+        let mut plain = Style::new();
+        plain.no_color_codes = true;
+        let a = panel(&style, "a");
+        let b = panel(&plain, "b");
+        let c = panel(&plain, "c");
+
+        let mut grid = Grid::new();
+        grid.width = Some(10);
+        grid.add(&a);
+        grid.add(&b);
+        grid.add(&c);
+
+        assert_eq!(1, grid.columns());
+        grid.width = Some(11);
+        assert_eq!(2, grid.columns());
+        let expected = "┌──┐  ┌──┐ \r\n│a │░ │b │ \r\n└──┘░ └──┘ \r\n ░░░░      \r\n┌──┐       \r\n│c │       \r\n└──┘       \r\n";
+        assert_eq!(expected, grid.assemble());
+    }
+}
@@ -0,0 +1,7 @@
+/// Describes where content sits within a row that is wider than the content.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HorizontalAlignment {
+    Left,
+    Center,
+    Right,
+}
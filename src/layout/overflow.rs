@@ -0,0 +1,8 @@
+/// Describes what happens to rows that do not fit within a banner's maximum height.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Overflow {
+    /// Drops the rows that do not fit.
+    Clip,
+    /// Replaces the rows that do not fit with a "… N more lines" row.
+    Elide,
+}
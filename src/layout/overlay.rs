@@ -0,0 +1,95 @@
+use crate::rendering::{Canvas, Joins};
+use crate::Banner;
+
+/// Stamps banners on top of a base banner, such as a popup or a badge on a panel.
+///
+/// Layers are drawn in the order they were added. Where the border of a layer crosses or
+/// touches the border beneath it the two are joined with junction glyphs, and anything that
+/// falls outside the base banner is clipped. Scaled lines in a layer are drawn at their normal
+/// size.
+pub struct Overlay<'a, 'b> {
+    base: &'b Banner<'a>,
+    layers: Vec<(&'b Banner<'a>, isize, isize)>,
+}
+
+impl<'a, 'b> Overlay<'a, 'b> {
+    /// Creates a new overlay with nothing stamped on the base banner.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The banner the layers are drawn on top of.
+    pub fn new(base: &'b Banner<'a>) -> Overlay<'a, 'b> {
+        Overlay { base, layers: Vec::new() }
+    }
+
+    /// Stamps a banner on top of the base banner and any layers added before it.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The overlay to add the banner to.
+    /// * `banner` - The banner to stamp.
+    /// * `row` - The row of the base banner the top edge of the banner is placed on.
+    /// * `column` - The column of the base banner the left edge of the banner is placed on.
+    pub fn add(&mut self, banner: &'b Banner<'a>, row: isize, column: isize) {
+        self.layers.push((banner, row, column));
+    }
+
+    /// Renders the overlay as a list of rows, without line endings.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The overlay to render.
+    pub fn rows(&self) -> Vec<String> {
+        self.canvas().to_ansi_rows()
+    }
+
+    /// Renders the overlay into a canvas of styled cells, the size of the base banner.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The overlay to render.
+    pub fn canvas(&self) -> Canvas {
+        let mut canvas = self.base.canvas();
+        for (banner, row, column) in self.layers.iter() {
+            let layer = banner.render(banner.inner_width_for(false), 0, Joins::default(), false);
+            canvas.overlay(&banner.cast_shadow(layer), *column, *row);
+        }
+
+        canvas
+    }
+
+    /// Assembles the overlay.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The overlay to assemble.
+    pub fn assemble(&self) -> String {
+        self.rows().iter().map(|row| format!("{}\r\n", row)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Style;
+
+    /// Verifies that a badge straddling the border is joined to it and clipped at the edge.
+    #[test]
+    fn test_assemble_badge() {
+        let mut style = Style::new();
+        style.no_color_codes = true;
+
+        let mut panel = Banner::new(&style);
+        panel.width = 8;
+        panel.add_text("a\nb\nc");
+        let mut badge = Banner::new(&style);
+        badge.width = 5;
+        badge.add_text("STALE");
+
+        let mut overlay = Overlay::new(&panel);
+        overlay.add(&badge, 0, 5);
+
+        let expected = "┌────┬───┬\r\n│a   │STAL\r\n│b   └───┼\r\n│c       │\r\n└────────┘\r\n";
+        assert_eq!(expected, overlay.assemble());
+    }
+}
@@ -0,0 +1,194 @@
+use super::join_top_to_bottom;
+use crate::rendering::{Canvas, Joins};
+use crate::Banner;
+
+/// Stacks banners vertically as sections of a single box.
+///
+/// Adjacent sections share one edge, joined with junction glyphs that keep the weight of both
+/// sections' borders (e.g. `├──┤`, or `┢━━┪` where a heavy section follows a light one), and
+/// every section is widened to the width of the widest section. The box casts the shadow of
+/// the first section's style.
+pub struct Stack<'a, 'b> {
+    banners: Vec<&'b Banner<'a>>,
+}
+
+impl<'a, 'b> Stack<'a, 'b> {
+    /// Creates a new, empty stack.
+    pub fn new() -> Stack<'a, 'b> {
+        Stack { banners: Vec::new() }
+    }
+
+    /// Adds a banner as a section below the existing sections.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The stack to add the banner to.
+    /// * `banner` - The banner to add.
+    pub fn add(&mut self, banner: &'b Banner<'a>) {
+        self.banners.push(banner);
+    }
+
+    /// Renders the stack as a list of rows, without line endings.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The stack to render.
+    pub fn rows(&self) -> Vec<String> {
+        self.canvas().to_ansi_rows()
+    }
+
+    /// Renders the stack into a canvas of styled cells.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The stack to render.
+    pub fn canvas(&self) -> Canvas {
+        let width = self.banners.iter().map(|banner| banner.inner_width()).max().unwrap_or(0);
+        let last = self.banners.len().saturating_sub(1);
+
+        let sections: Vec<Canvas> = self
+            .banners
+            .iter()
+            .enumerate()
+            .map(|(i, banner)| {
+                let joins = Joins {
+                    top: i > 0,
+                    bottom: i < last,
+                    ..Joins::default()
+                };
+                banner.render(width, 0, joins, true)
+            })
+            .collect();
+
+        let mut joined = join_top_to_bottom(&sections, 0);
+
+        // Join each shared edge to the lines of the section above it
+        let mut y = 0;
+        for section in sections.iter().take(last) {
+            y += section.height();
+            for x in 0..joined.width() {
+                joined.connect(x, y);
+            }
+        }
+        match self.banners.first() {
+            Some(first) => first.cast_shadow(joined),
+            None => joined,
+        }
+    }
+
+    /// Assembles the stack.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The stack to assemble.
+    pub fn assemble(&self) -> String {
+        self.rows().iter().map(|row| format!("{}\r\n", row)).collect()
+    }
+}
+
+impl Default for Stack<'_, '_> {
+    fn default() -> Self {
+        Stack::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::{BorderGlyphs, ShadowStyle};
+    use crate::Style;
+
+    /// Verifies that sections are joined with shared edges and synchronized to the widest section.
+    #[test]
+    fn test_assemble_sections() {
+        let mut style = Style::new();
+        style.no_color_codes = true;
+
+        let mut header = Banner::new(&style);
+        header.width = 2;
+        header.add_text("Deploy");
+        let mut details = Banner::new(&style);
+        details.width = 2;
+        details.set_title("Info");
+        details.add_text("ok");
+        let mut footer = Banner::new(&style);
+        footer.width = 2;
+        footer.add_text("end");
+
+        let mut stack = Stack::new();
+        stack.add(&header);
+        stack.add(&details);
+        stack.add(&footer);
+
+        let expected = "┌────────┐\r\n│Deploy  │\r\n├─ Info ─┤\r\n│ok      │\r\n├────────┤\r\n│end     │\r\n└────────┘\r\n";
+        assert_eq!(expected, stack.assemble());
+    }
+
+    /// Verifies that shared edges are joined with junctions matching the weight of each line.
+    #[test]
+    fn test_assemble_mixed_weights() {
+        let mut style = Style::new();
+        style.no_color_codes = true;
+        style.border.glyphs = BorderGlyphs::heavy();
+        style.border.glyphs.top = String::from("─");
+
+        let mut top = Banner::new(&style);
+        top.width = 2;
+        top.add_text("a");
+        let mut bottom = Banner::new(&style);
+        bottom.width = 2;
+        bottom.add_text("b");
+
+        let mut stack = Stack::new();
+        stack.add(&top);
+        stack.add(&bottom);
+
+        let expected = "┏──┓\r\n┃a ┃\r\n┠──┨\r\n┃b ┃\r\n┗━━┛\r\n";
+        assert_eq!(expected, stack.assemble());
+    }
+
+    /// Verifies that sections with different border styles join the weights of both.
+    #[test]
+    fn test_assemble_mixed_styles() {
+        let mut light = Style::new();
+        light.no_color_codes = true;
+        let mut heavy = Style::new();
+        heavy.no_color_codes = true;
+        heavy.border.glyphs = BorderGlyphs::heavy();
+
+        let mut top = Banner::new(&light);
+        top.width = 2;
+        top.add_text("a");
+        let mut bottom = Banner::new(&heavy);
+        bottom.width = 2;
+        bottom.add_text("b");
+
+        let mut stack = Stack::new();
+        stack.add(&top);
+        stack.add(&bottom);
+
+        let expected = "┌──┐\r\n│a │\r\n┢━━┪\r\n┃b ┃\r\n┗━━┛\r\n";
+        assert_eq!(expected, stack.assemble());
+    }
+
+    /// Verifies that the stacked box casts a single shadow.
+    #[test]
+    fn test_assemble_shadow() {
+        let mut style = Style::new();
+        style.no_color_codes = true;
+        style.shadow = Some(ShadowStyle::new());
+        let mut top = Banner::new(&style);
+        top.width = 2;
+        top.add_text("a");
+        let mut bottom = Banner::new(&style);
+        bottom.width = 2;
+        bottom.add_text("b");
+
+        let mut stack = Stack::new();
+        stack.add(&top);
+        stack.add(&bottom);
+
+        let expected = "┌──┐ \r\n│a │░\r\n├──┤░\r\n│b │░\r\n└──┘░\r\n ░░░░\r\n";
+        assert_eq!(expected, stack.assemble());
+    }
+}
@@ -0,0 +1,7 @@
+/// Describes where content sits within a banner that is taller than its content.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VerticalAlignment {
+    Top,
+    Middle,
+    Bottom,
+}
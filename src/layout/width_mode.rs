@@ -0,0 +1,71 @@
+use crate::terminal;
+
+/// Describes how the content width of a banner is chosen.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WidthMode {
+    /// Uses the banner's `width`, widening to fit content when `auto_widen` is set, but no
+    /// wider than the terminal when its width is known.
+    Auto,
+    /// Always uses the given width, clipping content that does not fit.
+    Fixed(u8),
+    /// Uses the width of the widest line, but no wider than the terminal when its width is known.
+    FitContent,
+    /// Fits the content, but no narrower than `min` and no wider than `max`.
+    Range { min: u8, max: u8 },
+    /// Uses a percentage of the terminal width, including borders.
+    TerminalPercent(u8),
+    /// Fills the terminal width, including borders.
+    FillTerminal,
+}
+
+impl WidthMode {
+    /// Returns the content width chosen by this mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The width mode.
+    /// * `width` - The width used by `Auto`.
+    /// * `content` - Returns the width of the content, used by the modes that fit the content.
+    /// * `terminal` - Returns the width of the terminal, if known. The terminal modes assume 80
+    ///   columns when it is not, while `Auto` and `FitContent` only stop at the terminal edge
+    ///   when the width is known.
+    /// * `frame` - The number of columns around the content (e.g. borders), which is left room
+    ///   for within the terminal.
+    pub fn resolve<C, T>(self: &Self, width: u8, content: C, terminal: T, frame: u8) -> u8
+    where
+        C: Fn() -> u8,
+        T: Fn() -> Option<u8>,
+    {
+        let clip = |width: u8| terminal().map_or(width, |columns| width.min(columns.saturating_sub(frame)));
+        let columns = || terminal().unwrap_or(terminal::FALLBACK_WIDTH.min(u8::MAX as u16) as u8);
+        match *self {
+            WidthMode::Auto => clip(width),
+            WidthMode::Fixed(width) => width,
+            WidthMode::FitContent => clip(content()),
+            WidthMode::Range { min, max } => content().max(min).min(max),
+            WidthMode::TerminalPercent(percent) => {
+                let columns = columns() as u32 * percent.min(100) as u32 / 100;
+                (columns as u8).saturating_sub(frame)
+            }
+            WidthMode::FillTerminal => columns().saturating_sub(frame),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verifies that widths are only stopped at the terminal edge when the terminal width is
+    /// known, so that piped output is never truncated.
+    #[test]
+    fn test_resolve_unknown_terminal() {
+        let content = || 100;
+        assert_eq!(100, WidthMode::Auto.resolve(100, content, || None, 2));
+        assert_eq!(100, WidthMode::FitContent.resolve(0, content, || None, 2));
+        assert_eq!(78, WidthMode::FillTerminal.resolve(0, content, || None, 2));
+
+        assert_eq!(38, WidthMode::Auto.resolve(100, content, || Some(40), 2));
+        assert_eq!(38, WidthMode::FitContent.resolve(0, content, || Some(40), 2));
+    }
+}
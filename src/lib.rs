@@ -1,46 +1,109 @@
+#![allow(clippy::needless_arbitrary_self_type)]
+
 mod content;
+mod figlet;
+mod layout;
 mod rendering;
+mod rule;
 mod style;
+mod terminal;
+mod text;
 
-use content::{KeyValueLine, Line, TextLine};
-use rendering::BorderPainter;
-pub use style::{Color, HeaderLevel, Style};
+use content::{markup, BannerLine, BlankLine, FigletLine, KeyValueLine, Line, RichTextLine, ScaledLine, TextLine};
+pub use content::Span;
+pub use figlet::{FigletFont, FontError};
+pub use layout::{BannerGroup, Columns, Grid, HorizontalAlignment, Overflow, Overlay, Stack, VerticalAlignment, WidthMode};
+pub use rule::Rule;
+pub use rendering::{Attributes, Canvas, Cell, CellStyle, Connections, LineSize, LineWeight};
+use rendering::{BorderPainter, Joins, ShadowPainter};
+pub use style::{
+    AnsiInput, BorderColors, BorderGlyphs, BorderPart, BorderStyle, Color, ColorSupport, ElementStyle, Gradient, GradientDirection, HeaderLevel, LineScale,
+    Numbering, SanitizePolicy, ShadowStyle, Style, TextEffect, TextTransform,
+};
+use text::{ansi, lines};
 
 pub struct Banner<'a> {
     pub width: u8,
     pub auto_widen: bool,
+    /// Describes how the content width is chosen when the banner is assembled.
+    pub width_mode: WidthMode,
+    /// Overrides the detected terminal width used by the width modes.
+    pub terminal_width: Option<u16>,
+    /// The minimum number of content rows. Shorter content is padded with blank rows.
+    pub min_height: Option<u8>,
+    /// The maximum number of content rows. Set together with `min_height` for a fixed height.
+    pub max_height: Option<u8>,
+    /// Describes where content sits when the banner is taller than its content.
+    pub vertical_alignment: VerticalAlignment,
+    /// Describes what happens to rows beyond the maximum height.
+    pub overflow: Overflow,
+    /// The number of spaces between the left and right borders and the content.
+    pub padding: u8,
+    /// Overrides the detected support for double width and double height lines. Support is
+    /// checked as lines are added; without it, scaled lines are drawn at their normal size.
+    pub line_scale_support: Option<bool>,
     style: &'a Style,
+    title: Option<String>,
     lines: Vec<Box<dyn Line + 'a>>,
+    has_scaled_lines: bool,
+    /// The number of headers added at each level since the last header at a higher level.
+    header_counts: [usize; 6],
 }
 
 impl<'a> Banner<'a> {
     /// Creates a new banner with default values.
     pub fn new(style: &'a Style) -> Banner<'a> {
-        return Banner {
+        Banner {
             width: 50,
             auto_widen: true,
-            style: style,
+            width_mode: WidthMode::Auto,
+            terminal_width: None,
+            min_height: None,
+            max_height: None,
+            vertical_alignment: VerticalAlignment::Top,
+            overflow: Overflow::Elide,
+            padding: 0,
+            line_scale_support: None,
+            style,
+            title: None,
             lines: Vec::new(),
-        };
+            has_scaled_lines: false,
+            header_counts: [0; 6],
+        }
+    }
+
+    /// Sets a title shown within the top border of the banner.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The banner to set the title of.
+    /// * `title` - The title text.
+    pub fn set_title(&mut self, title: &str) {
+        let title = ansi::strip(&self.style.prepare(title));
+        self.title = Some(title);
+
+        // Check if banner needs to be widened
+        let title_width = self.title_width();
+        if self.auto_widen && title_width > self.width {
+            self.width = title_width
+        }
     }
 
     /// Adds a header to the banner.
     ///
+    /// If the header style has a numbering style, the header is numbered after the headers
+    /// added before it (e.g. `1.` for the first H1 and `1.2` for the second H2 below it).
+    ///
     /// # Arguments
     ///
     /// * `self` - The banner to add the line of text to.
     /// * `text` - The text content of the header.
     /// * `level` - The header level.
     pub fn add_header<'b>(&'b mut self, text: &'a str, level: HeaderLevel) {
-        let line = TextLine::new(text, &self.style.header_style(&level));
-
-        // Check if banner needs to be widened
-        let line_width = line.width();
-        if self.auto_widen && line_width > self.width {
-            self.width = line_width
+        match self.number_header(level) {
+            Some(number) => self.add_text_rows(&format!("{} {}", number, text), self.style.header_style(&level)),
+            None => self.add_text_rows(text, self.style.header_style(&level)),
         }
-
-        self.lines.push(Box::new(line));
     }
 
     /// Adds a line of text to the banner.
@@ -50,18 +113,103 @@ impl<'a> Banner<'a> {
     /// * `self` - The banner to add the line of text to.
     /// * `text` - The text to add.
     pub fn add_text<'b>(&'b mut self, text: &'a str) {
-        let line = TextLine::new(text, &self.style.text);
+        self.add_text_rows(text, &self.style.text);
+    }
 
-        // Check if banner needs to be widened
-        let line_width = line.width();
-        if self.auto_widen && line_width > self.width {
-            self.width = line_width
+    /// Adds a block of text to the banner, wrapping each line to the content width.
+    ///
+    /// Content is sanitized before it is wrapped, so that escaped control characters are
+    /// counted in the width. Fitted width modes wrap to the banner's `width`, or to the
+    /// maximum of a range.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The banner to add the paragraph to.
+    /// * `text` - The text to add. Line breaks start new rows.
+    pub fn add_paragraph<'b>(&'b mut self, text: &'a str) {
+        for row in lines::split_lines(text) {
+            let row = self.style.prepare(&lines::expand_tabs(row, self.style.tab_width));
+            for wrapped in lines::wrap(&row, self.wrap_width() as usize) {
+                let line = TextLine::new(&wrapped, &self.style.text);
+                self.push_line(Box::new(line));
+            }
+        }
+    }
+
+    /// Adds a line of text styled by a named style class.
+    ///
+    /// Falls back to the text style if no class with that name is registered.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The banner to add the line of text to.
+    /// * `text` - The text to add.
+    /// * `class` - The name of the style class registered on the banner style.
+    pub fn add_text_with_class<'b>(&'b mut self, text: &'a str, class: &str) {
+        let element_style = self.style.class_style(class).unwrap_or(&self.style.text);
+        self.add_text_rows(text, element_style);
+    }
+
+    /// Adds a line of text with its own element style, overriding the banner style for that row.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The banner to add the line of text to.
+    /// * `text` - The text to add.
+    /// * `style` - The element style to apply to this line only.
+    pub fn add_text_styled<'b>(&'b mut self, text: &'a str, style: &'a ElementStyle) {
+        self.add_text_rows(text, style);
+    }
+
+    /// Adds a line of text made up of individually styled spans, expanding tabs.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The banner to add the line to.
+    /// * `spans` - The spans making up the line.
+    pub fn add_spans(&mut self, mut spans: Vec<Span>) {
+        let mut column = 0;
+        for span in spans.iter_mut() {
+            span.text = self.style.prepare(&lines::expand_tabs_at(&span.text, self.style.tab_width, column));
+            column += ansi::visible_width(&span.text);
         }
+        let line = RichTextLine::new(spans, &self.style.text);
+        self.push_line(Box::new(line));
+    }
 
-        self.lines.push(Box::new(line));
+    /// Adds a line of text written in inline markup (e.g. "Build **passed** in {green}42s{/}").
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The banner to add the line to.
+    /// * `text` - The markup to parse into styled spans.
+    pub fn add_markup(&mut self, text: &str) {
+        self.add_spans(markup::parse(text));
+    }
+
+    /// Adds an empty spacer row to the banner.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The banner to add the row to.
+    pub fn add_blank(&mut self) {
+        self.push_line(Box::new(BlankLine));
+    }
+
+    /// Adds a complete banner as content, drawn inside this banner's borders and padding.
+    ///
+    /// The nested banner is stretched to fill the content width, and this banner is widened
+    /// to fit the nested banner if necessary.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The banner to add the nested banner to.
+    /// * `banner` - The banner to nest.
+    pub fn add_banner(&mut self, banner: Banner<'a>) {
+        self.push_line(Box::new(BannerLine::new(banner)));
     }
 
-    /// Adds a line showing a key value pair to the banner.
+    /// Adds a line showing a key value pair to the banner, expanding tabs.
     ///
     /// # Arguments
     ///
@@ -69,15 +217,45 @@ impl<'a> Banner<'a> {
     /// * `key` - The key name.
     /// * `value` - The value as text.
     pub fn add_key_value<'b>(&'b mut self, key: &'a str, value: &'a str) {
-        let line = KeyValueLine::new(key, value, &self.style.text);
+        let (key, value) = self.prepare_key_value(key, value);
+        let line = KeyValueLine::new(&key, &value, &self.style.text);
+        self.push_line(Box::new(line));
+    }
 
-        // Check if banner needs to be widened
-        let line_width = line.width();
-        if self.auto_widen && line_width > self.width {
-            self.width = line_width
-        }
+    /// Adds a key value line with its own styles for the key and the value, expanding tabs.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The banner to add the line to.
+    /// * `key` - The key name.
+    /// * `value` - The value as text.
+    /// * `key_style` - The element style to apply to the key.
+    /// * `value_style` - The element style to apply to the value.
+    pub fn add_key_value_styled<'b>(
+        &'b mut self,
+        key: &'a str,
+        value: &'a str,
+        key_style: &'a ElementStyle,
+        value_style: &'a ElementStyle,
+    ) {
+        let (key, value) = self.prepare_key_value(key, value);
+        let line = KeyValueLine::with_styles(&key, &value, key_style, value_style);
+        self.push_line(Box::new(line));
+    }
 
-        self.lines.push(Box::new(line));
+    /// Prepares a key and value for display, expanding tabs in the value from the column it
+    /// starts at after the key.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The banner whose style prepares the text.
+    /// * `key` - The key name.
+    /// * `value` - The value as text.
+    fn prepare_key_value(self: &Banner<'a>, key: &str, value: &str) -> (String, String) {
+        let key = self.style.prepare(&lines::expand_tabs(key, self.style.tab_width));
+        let column = ansi::visible_width(&key) + 2;
+        let value = self.style.prepare(&lines::expand_tabs_at(value, self.style.tab_width, column));
+        (key, value)
     }
 
     /// Assembles the banner.
@@ -86,32 +264,403 @@ impl<'a> Banner<'a> {
     ///
     /// * `self` - The banner to assemble.
     pub fn assemble(self: &Banner<'a>) -> String {
+        self.rows().iter().map(|row| format!("{}\r\n", row)).collect()
+    }
+
+    /// Renders the banner as a list of rows, without line endings.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The banner to render.
+    pub fn rows(self: &Banner<'a>) -> Vec<String> {
+        self.canvas().to_ansi_rows()
+    }
+
+    /// Renders the banner into a canvas of styled cells.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The banner to render.
+    pub fn canvas(self: &Banner<'a>) -> Canvas {
+        self.cast_shadow(self.render(self.inner_width(), 0, Joins::default(), true))
+    }
+
+    /// Returns the rendered width of the banner, including its borders and shadow.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The banner to measure.
+    pub fn outer_width(self: &Banner<'a>) -> usize {
+        self.inner_width() as usize + self.border_width() + self.shadow_size().0
+    }
+
+    /// Returns the number of columns and rows the shadow adds to the banner.
+    pub(crate) fn shadow_size(self: &Banner<'a>) -> (usize, usize) {
+        self.style.shadow.as_ref().map_or((0, 0), |shadow| (shadow.offset_x as usize, shadow.offset_y as usize))
+    }
+
+    /// Returns the rendered banner with the style's shadow cast behind it, if it has one.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The banner whose style describes the shadow.
+    /// * `canvas` - The rendered banner.
+    pub(crate) fn cast_shadow(self: &Banner<'a>, canvas: Canvas) -> Canvas {
+        match &self.style.shadow {
+            Some(shadow) => ShadowPainter::new(shadow, self.style.no_color_codes).paint(&canvas),
+            None => canvas,
+        }
+    }
+
+    /// Returns the number of columns taken by the left and right borders together.
+    pub(crate) fn border_width(self: &Banner<'a>) -> usize {
+        let glyphs = &self.style.border.glyphs;
+        glyphs.left_width() + glyphs.right_width()
+    }
+
+    /// Returns the width between the left and right borders, including padding.
+    pub(crate) fn inner_width(self: &Banner<'a>) -> u8 {
+        self.inner_width_for(true)
+    }
+
+    /// Returns the width between the left and right borders, including padding, when drawn
+    /// either across whole rows or within a layout or another banner.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The banner to measure.
+    /// * `full_rows` - True if the banner spans whole rows, so that scaled lines are drawn at
+    ///   their scale.
+    pub(crate) fn inner_width_for(self: &Banner<'a>, full_rows: bool) -> u8 {
+        let width = self.content_width_for(full_rows).saturating_add(self.padding.saturating_mul(2));
+
+        // Rows drawn at double width need an even number of columns to reach the right edge
+        if full_rows && self.has_scaled_lines && (width as usize + self.border_width()) % 2 == 1 {
+            width.saturating_add(1)
+        } else {
+            width
+        }
+    }
+
+    /// Returns the number of content rows the banner renders within a layout or another banner,
+    /// excluding its borders.
+    pub(crate) fn body_height(self: &Banner<'a>) -> usize {
+        self.body(self.content_width_for(false), 0, false).height()
+    }
+
+    /// Renders the banner with a given inner width and minimum number of content rows.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The banner to render.
+    /// * `width` - The width between the left and right borders, including padding.
+    /// * `min_rows` - The minimum number of content rows, in addition to the banner's own minimum.
+    /// * `joins` - The edges shared with neighbouring banners.
+    /// * `full_rows` - True if the banner spans whole rows, so that scaled lines are drawn at
+    ///   their scale.
+    pub(crate) fn render(self: &Banner<'a>, width: u8, min_rows: usize, joins: Joins, full_rows: bool) -> Canvas {
         let border_painter: BorderPainter =
-            BorderPainter::new(&self.style.border, self.style.no_color_codes, self.width);
-
-        let mut result: String;
-        result = format!("{}\r\n", border_painter.top());
-        for line in self.lines.iter() {
-            let line_text = &(*line).fmt(self.style.no_color_codes);
-            let line_width = (*line).width();
-            // Add left border
-            result.push_str(&border_painter.left());
-            // Add line content
-            result.push_str(&format!("{}", line_text)[..]);
-            // Add whitespace to end
-            result.push_str(&format!(
-                "{}",
-                (line_width as usize..self.width as usize)
-                    .map(|_| " ")
-                    .collect::<String>()
-            ));
-            // Add right border
-            result.push_str(&border_painter.right());
-            result.push_str("\r\n");
-        }
-        result.push_str(&format!("{}\r\n", border_painter.bottom())[..]);
-
-        result
+            BorderPainter::new(&self.style.border, self.style.no_color_codes, width);
+        let glyphs = &self.style.border.glyphs;
+        let left_corner = |corner: &'_ str| if joins.left { None } else { Some(corner.to_string()) };
+
+        let padding = self.padding.min(width / 2);
+        let body = self.body(width - 2 * padding, min_rows, full_rows);
+        let left = if joins.left { 0 } else { glyphs.left_width() };
+        let bottom = if joins.bottom { 0 } else { 1 };
+        let mut canvas = Canvas::new(left + width as usize + glyphs.right_width(), 1 + body.height() + bottom);
+        let right = left + width as usize;
+
+        // Add top border
+        let junction = |up, down, left, right, fallback: &str| {
+            border_painter
+                .junction(up, down, left, right)
+                .map_or_else(|| fallback.to_string(), String::from)
+        };
+        let (top_left, top_right) = match (joins.top, joins.right) {
+            (true, true) => (junction(true, true, false, true, &glyphs.left_junction), junction(true, true, true, true, &glyphs.cross)),
+            (true, false) => (junction(true, true, false, true, &glyphs.left_junction), junction(true, true, true, false, &glyphs.right_junction)),
+            (false, true) => (glyphs.top_left.clone(), junction(false, true, true, true, &glyphs.top_junction)),
+            (false, false) => (glyphs.top_left.clone(), glyphs.top_right.clone()),
+        };
+        let title = self.title.as_deref();
+        border_painter.paint_edge(&mut canvas, 0, 0, BorderPart::Top, left_corner(&top_left).as_deref(), &glyphs.top, title, Some(&top_right));
+
+        // Add content, between the left and right borders
+        canvas.blit(&body, left + padding as usize, 1);
+        for y in 0..body.height() {
+            let size = body.line_size(y);
+            if size != LineSize::Single {
+                canvas.set_line_size(1 + y, size, glyphs.right_width());
+            }
+        }
+        for y in 1..=body.height() {
+            if !joins.left {
+                border_painter.paint_glyph(&mut canvas, 0, y, BorderPart::Left, &glyphs.left);
+            }
+            border_painter.paint_glyph(&mut canvas, right, y, BorderPart::Right, &glyphs.right);
+        }
+
+        // Add bottom border
+        if !joins.bottom {
+            let bottom_right = if joins.right {
+                junction(true, false, true, true, &glyphs.bottom_junction)
+            } else {
+                glyphs.bottom_right.clone()
+            };
+            let y = canvas.height() - 1;
+            let bottom_left = left_corner(&glyphs.bottom_left);
+            border_painter.paint_edge(&mut canvas, 0, y, BorderPart::Bottom, bottom_left.as_deref(), &glyphs.bottom, None, Some(&bottom_right));
+        }
+
+        if self.style.no_color_codes {
+            canvas.clear_styles();
+        }
+        canvas
+    }
+
+    /// Draws the content rows of the banner, applying the height limits and vertical alignment.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The banner to draw.
+    /// * `width` - The width of the content area.
+    /// * `min_rows` - The minimum number of content rows, in addition to the banner's own minimum.
+    /// * `full_rows` - True if the banner spans whole rows, so that scaled lines are drawn at
+    ///   their scale.
+    fn body(self: &Banner<'a>, width: u8, min_rows: usize, full_rows: bool) -> Canvas {
+        let lines: Vec<&dyn Line> = self.lines_for(full_rows).collect();
+        let total: usize = lines.iter().map(|line| line.height()).sum();
+        let max = self.max_height.map_or(usize::MAX, |max| max as usize);
+
+        // Work out how many rows fit and how many are elided
+        let mut visible = total.min(max);
+        let mut elided = 0;
+        if total > max && max > 0 && self.overflow == Overflow::Elide {
+            visible = max - 1;
+            elided = total - visible;
+
+            // Elide a scaled line that straddles the last visible row as a whole
+            if let Some(start) = Banner::straddling_line(&lines, visible) {
+                visible = start;
+                elided = total - start;
+            }
+        }
+        let shown = visible + if elided > 0 { 1 } else { 0 };
+
+        // Pad to the minimum height
+        let min = self.min_height.map_or(0, |min| min as usize).min(max).max(min_rows);
+        let missing = min.saturating_sub(shown);
+        let above = match self.vertical_alignment {
+            VerticalAlignment::Top => 0,
+            VerticalAlignment::Middle => missing / 2,
+            VerticalAlignment::Bottom => missing,
+        };
+
+        // Draw the lines that fit, clipping any line that straddles the last visible row. Scaled
+        // lines cannot be clipped, so they are drawn at their normal size instead.
+        let mut content = Canvas::new(width as usize, visible);
+        let mut y = 0;
+        for line in lines.iter() {
+            if y >= visible {
+                break;
+            }
+            match line.unscaled() {
+                Some(unscaled) if y + line.height() > visible => unscaled.draw(&mut content, 0, y, width),
+                _ => line.draw(&mut content, 0, y, width),
+            }
+            y += line.height();
+        }
+
+        let mut canvas = Canvas::new(width as usize, shown + missing);
+        canvas.blit(&content, 0, above);
+        if elided > 0 {
+            let noun = if elided == 1 { "line" } else { "lines" };
+            let text = format!("… {} more {}", elided, noun);
+            TextLine::new(&text, &self.style.text).draw(&mut canvas, 0, above + visible, width);
+        }
+
+        canvas
+    }
+
+    /// Returns the width of the content area, as chosen by the width mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The banner to measure.
+    pub fn content_width(self: &Banner<'a>) -> u8 {
+        self.content_width_for(true)
+    }
+
+    /// Returns the width of the content area when drawn either across whole rows or within a
+    /// layout or another banner.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The banner to measure.
+    /// * `full_rows` - True if the banner spans whole rows, so that scaled lines are drawn at
+    ///   their scale.
+    pub(crate) fn content_width_for(self: &Banner<'a>, full_rows: bool) -> u8 {
+        let widest = || {
+            let lines = self.lines_for(full_rows).map(|line| line.width()).max().unwrap_or(0);
+            lines.max(self.title_width())
+        };
+        let terminal = || {
+            let columns = self.terminal_width.or_else(terminal::detected_width);
+            columns.map(|columns| columns.min(u8::MAX as u16) as u8)
+        };
+        let frame = (self.border_width() + 2 * self.padding as usize).min(u8::MAX as usize) as u8;
+
+        // Scaled lines only widen the banner when they are drawn at their scale
+        let width = if full_rows && self.auto_widen && self.has_scaled_lines {
+            self.width.max(widest())
+        } else {
+            self.width
+        };
+        self.width_mode.resolve(width, widest, terminal, frame)
+    }
+
+    /// Returns the lines of the banner as drawn, with scaled lines at their normal size unless
+    /// the banner spans whole rows.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The banner whose lines to return.
+    /// * `full_rows` - True if the banner spans whole rows, so that scaled lines are drawn at
+    ///   their scale.
+    fn lines_for(self: &Banner<'a>, full_rows: bool) -> impl Iterator<Item = &dyn Line> + '_ {
+        self.lines.iter().map(move |line| match line.unscaled() {
+            Some(unscaled) if !full_rows => unscaled,
+            _ => line.as_ref(),
+        })
+    }
+
+    /// Returns the first row of the scaled line that straddles the given row, if there is one.
+    ///
+    /// # Arguments
+    ///
+    /// * `lines` - The lines of the banner, as drawn.
+    /// * `row` - The first row that is not shown.
+    fn straddling_line(lines: &[&dyn Line], row: usize) -> Option<usize> {
+        let mut y = 0;
+        for line in lines.iter() {
+            if y >= row {
+                break;
+            }
+            if line.unscaled().is_some() && y + line.height() > row {
+                return Some(y);
+            }
+            y += line.height();
+        }
+
+        None
+    }
+
+    /// Returns the width paragraphs are wrapped to.
+    ///
+    /// Modes that fit the content have no width to wrap to until the content is added, so they
+    /// wrap to the banner's `width`, or the maximum of a range.
+    fn wrap_width(self: &Banner<'a>) -> u8 {
+        match self.width_mode {
+            WidthMode::FitContent => self.width,
+            WidthMode::Range { max, .. } => max,
+            _ => self.content_width(),
+        }
+    }
+
+    /// Returns the content width needed to show the title in the top border.
+    fn title_width(self: &Banner<'a>) -> u8 {
+        // Leave room for a border glyph and a space on each side of the title
+        self.title.as_ref().map_or(0, |title| (ansi::visible_width(title) + 4).min(u8::MAX as usize) as u8)
+    }
+
+    /// Adds text as one row per line break, expanding tabs in each row.
+    ///
+    /// If the style has a FIGlet font, each row is rendered as large letters instead. If the
+    /// style has a line scale and the terminal supports it, each row is drawn at that scale.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The banner to add the rows to.
+    /// * `text` - The text to add.
+    /// * `style` - The element style applied to every row.
+    fn add_text_rows(self: &mut Banner<'a>, text: &str, style: &'a ElementStyle) {
+        for row in lines::split_lines(text) {
+            let row = lines::expand_tabs(row, self.style.tab_width);
+            match &style.font {
+                Some(font) => {
+                    let rows = font.render(&ansi::strip(&style.apply_transforms(&self.style.prepare(&row))));
+                    self.push_line(Box::new(FigletLine::new(rows, style)));
+                }
+                None if style.line_scale != LineScale::Normal && self.supports_line_scale() => {
+                    let line = TextLine::new(&self.style.prepare(&row), style);
+                    let frame = self.border_width().min(u8::MAX as usize) as u8;
+                    self.has_scaled_lines = true;
+                    self.push_line(Box::new(ScaledLine::new(line, style.line_scale, frame)));
+                }
+                None => {
+                    let line = TextLine::new(&self.style.prepare(&row), style);
+                    self.push_line(Box::new(line));
+                }
+            }
+        }
+    }
+
+    /// Counts a new header and returns its number, or None if the level is not numbered.
+    ///
+    /// Headers at every level are counted, so that an unnumbered header still restarts the
+    /// numbering of the levels below it.
+    ///
+    /// The number is made of the count at each numbered level down to the header's own, such
+    /// as `1.2`. Higher levels without headers yet are left out, and a number with a single
+    /// part ends with a period, such as `1.`.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The banner the header is being added to.
+    /// * `level` - The level of the new header.
+    fn number_header(self: &mut Banner<'a>, level: HeaderLevel) -> Option<String> {
+        // Every header restarts the numbering of the levels below it, numbered or not
+        let index = level.index();
+        self.header_counts[index] += 1;
+        for count in self.header_counts[index + 1..].iter_mut() {
+            *count = 0;
+        }
+        if self.style.header_style(&level).numbering == Numbering::None {
+            return None;
+        }
+
+        let parts: Vec<String> = HeaderLevel::ALL[..=index]
+            .iter()
+            .map(|level| (self.style.header_style(level).numbering, self.header_counts[level.index()]))
+            .filter(|(numbering, count)| *numbering != Numbering::None && *count > 0)
+            .map(|(numbering, count)| numbering.format(count))
+            .collect();
+        match parts.len() {
+            1 => Some(format!("{}.", parts[0])),
+            _ => Some(parts.join(".")),
+        }
+    }
+
+    /// Returns true if lines can be drawn at double width and double height.
+    fn supports_line_scale(self: &Banner<'a>) -> bool {
+        self.line_scale_support.unwrap_or_else(terminal::supports_line_sizes)
+    }
+
+    /// Adds a line to the banner, widening the banner to fit if necessary.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The banner to add the line to.
+    /// * `line` - The line to add.
+    fn push_line(self: &mut Banner<'a>, line: Box<dyn Line + 'a>) {
+        // Check if banner needs to be widened, leaving scaled lines to widen it when rendered
+        let line_width = line.unscaled().map_or_else(|| line.width(), |unscaled| unscaled.width());
+        if self.auto_widen && line_width > self.width {
+            self.width = line_width
+        }
+
+        self.lines.push(line);
     }
 }
 
@@ -180,56 +729,695 @@ mod tests {
         assert_eq!(expected, banner.assemble());
     }
 
+    /// Verifies that lines using a style class are colored by that class.
+    #[test]
+    fn test_assemble_text_with_class() {
+        let mut style: Style = Style::new();
+        style.border.color = Color::White;
+        let mut warning = ElementStyle::new();
+        warning.content_color = Color::Yellow;
+        style.add_class("warning", warning);
+
+        let mut banner: Banner = Banner::new(&style);
+        banner.width = 6;
+        banner.add_text_with_class("Warn", "warning");
+        banner.add_text_with_class("Text", "unknown");
+
+        let expected = "\u{1b}[37m┌──────┐\u{1b}[0m\r\n\u{1b}[37m│\u{1b}[0m\u{1b}[33mWarn\u{1b}[0m  \u{1b}[37m│\u{1b}[0m\r\n\u{1b}[37m│Text\u{1b}[0m  \u{1b}[37m│\u{1b}[0m\r\n\u{1b}[37m└──────┘\u{1b}[0m\r\n";
+        assert_eq!(expected, banner.assemble());
+    }
+
     // #endregion
 
-    /// Tests that an empty banner is assembled correctly.
+    /// Verifies that per-line style overrides apply only to their own rows.
     #[test]
-    fn test_assemble_empty() {
-        // Create a style
+    fn test_assemble_styled_overrides() {
         let mut style: Style = Style::new();
         style.border.color = Color::White;
+        let mut red = ElementStyle::new();
+        red.content_color = Color::Red;
+        let mut green = ElementStyle::new();
+        green.content_color = Color::Green;
 
-        // Build the banner
         let mut banner: Banner = Banner::new(&style);
-        banner.width = 4;
+        banner.width = 6;
+        banner.add_text_styled("Err", &red);
+        banner.add_key_value_styled("K", "V", &green, &red);
+        banner.add_text("Ok");
 
-        let expected = "\u{1b}[37m┌────┐\u{1b}[0m\r\n\u{1b}[37m└────┘\u{1b}[0m\r\n";
+        let expected = "\u{1b}[37m┌──────┐\u{1b}[0m\r\n\u{1b}[37m│\u{1b}[0m\u{1b}[31mErr\u{1b}[0m   \u{1b}[37m│\u{1b}[0m\r\n\u{1b}[37m│\u{1b}[0m\u{1b}[32mK: \u{1b}[0m\u{1b}[31mV\u{1b}[0m  \u{1b}[37m│\u{1b}[0m\r\n\u{1b}[37m│Ok\u{1b}[0m    \u{1b}[37m│\u{1b}[0m\r\n\u{1b}[37m└──────┘\u{1b}[0m\r\n";
         assert_eq!(expected, banner.assemble());
     }
 
-    /// Verifies that a banner with a single text line is assembled correctly.
+    /// Verifies that markup lines are sized on their visible text only.
     #[test]
-    fn test_assemble_simple() {
-        // Create a style
+    fn test_assemble_markup() {
         let mut style: Style = Style::new();
-        style.no_color_codes = true;
+        style.border.color = Color::White;
 
-        // Build the banner
         let mut banner: Banner = Banner::new(&style);
-        banner.width = 16;
-        banner.add_text("Hello!");
+        banner.width = 2;
+        banner.add_markup("A **b** {red}c{/}");
 
-        let expected = "┌────────────────┐\r\n│Hello!          │\r\n└────────────────┘\r\n";
+        let expected = "\u{1b}[37m┌─────┐\u{1b}[0m\r\n\u{1b}[37m│A \u{1b}[0m\u{1b}[1;37mb\u{1b}[0m\u{1b}[37m \u{1b}[0m\u{1b}[31mc\u{1b}[0m\u{1b}[37m│\u{1b}[0m\r\n\u{1b}[37m└─────┘\u{1b}[0m\r\n";
         assert_eq!(expected, banner.assemble());
     }
 
-    /// Verifies that a banner with a single text line is assembled correctly.
+    /// Verifies that pre-colored input is measured on its visible text and kept out of the border.
     #[test]
-    fn test_assemble_simple_colored() {
-        // Create a style
+    fn test_assemble_preserves_ansi_input() {
         let mut style: Style = Style::new();
         style.border.color = Color::White;
         style.text.content_color = Color::Red;
 
-        // Build a banner
         let mut banner: Banner = Banner::new(&style);
-        banner.width = 16;
-
-        // Add multiple lines of text
-        banner.add_text("Hello, ");
-        banner.add_text("World!");
+        banner.width = 2;
+        banner.add_text("a\u{1b}[32mb\u{1b}[0mc");
 
-        let expected = "\u{1b}[37m┌────────────────┐\u{1b}[0m\r\n\u{1b}[37m│\u{1b}[0m\u{1b}[31mHello, \u{1b}[0m         \u{1b}[37m│\u{1b}[0m\r\n\u{1b}[37m│\u{1b}[0m\u{1b}[31mWorld!\u{1b}[0m          \u{1b}[37m│\u{1b}[0m\r\n\u{1b}[37m└────────────────┘\u{1b}[0m\r\n";
+        let expected = "\u{1b}[37m┌───┐\u{1b}[0m\r\n\u{1b}[37m│\u{1b}[0m\u{1b}[31ma\u{1b}[0m\u{1b}[32mb\u{1b}[0m\u{1b}[31mc\u{1b}[0m\u{1b}[37m│\u{1b}[0m\r\n\u{1b}[37m└───┘\u{1b}[0m\r\n";
         assert_eq!(expected, banner.assemble());
     }
+
+    /// Verifies that incoming escape sequences can be stripped.
+    #[test]
+    fn test_assemble_strips_ansi_input() {
+        let mut style: Style = Style::new();
+        style.no_color_codes = true;
+        style.ansi_input = AnsiInput::Strip;
+
+        let mut banner: Banner = Banner::new(&style);
+        banner.width = 2;
+        banner.add_key_value("\u{1b}[1mK\u{1b}[0m", "\u{1b}[32mV\u{1b}[0m");
+
+        assert_eq!("┌────┐\r\n│K: V│\r\n└────┘\r\n", banner.assemble());
+    }
+
+    /// Verifies that malicious input is escaped by default and cannot break the border.
+    #[test]
+    fn test_assemble_sanitizes_untrusted_input() {
+        let mut style: Style = Style::new();
+        style.no_color_codes = true;
+
+        let mut banner: Banner = Banner::new(&style);
+        banner.width = 2;
+        banner.add_key_value("ip", "1.2.3.4\r\u{1b}[2Kadmin\n");
+
+        let expected = "┌─────────────────────────────┐\r\n│ip: 1.2.3.4\\r\\u{1b}[2Kadmin\\n│\r\n└─────────────────────────────┘\r\n";
+        assert_eq!(expected, banner.assemble());
+    }
+
+    /// Verifies that tabs in key value lines and spans expand to the tab stops of the row.
+    #[test]
+    fn test_assemble_tabs_in_key_values_and_spans() {
+        let mut style: Style = Style::new();
+        style.no_color_codes = true;
+        style.tab_width = 4;
+
+        let mut banner: Banner = Banner::new(&style);
+        banner.width = 2;
+        banner.add_key_value("k", "a\tb");
+        banner.add_spans(vec![Span::new("ab\t"), Span::new("c\td")]);
+
+        assert_eq!("┌─────────┐\r\n│k: a    b│\r\n│ab  c   d│\r\n└─────────┘\r\n", banner.assemble());
+    }
+
+    /// Verifies that unsafe characters can be stripped instead of escaped.
+    #[test]
+    fn test_assemble_sanitize_strip() {
+        let mut style: Style = Style::new();
+        style.no_color_codes = true;
+        style.sanitize = SanitizePolicy::Strip;
+
+        let mut banner: Banner = Banner::new(&style);
+        banner.width = 2;
+        banner.add_text("a\rb\u{7}\u{1b}]0;x\u{7}");
+
+        assert_eq!("┌──────┐\r\n│ab]0;x│\r\n└──────┘\r\n", banner.assemble());
+    }
+
+    /// Verifies that embedded line breaks become separate rows and tabs are expanded.
+    #[test]
+    fn test_assemble_multi_line_text() {
+        let mut style: Style = Style::new();
+        style.no_color_codes = true;
+        style.tab_width = 2;
+
+        let mut banner: Banner = Banner::new(&style);
+        banner.width = 2;
+        banner.add_text("line one\r\nline two\n\tx");
+
+        let expected = "┌────────┐\r\n│line one│\r\n│line two│\r\n│  x     │\r\n└────────┘\r\n";
+        assert_eq!(expected, banner.assemble());
+    }
+
+    /// Verifies that paragraphs are wrapped to the banner width.
+    #[test]
+    fn test_assemble_paragraph() {
+        let mut style: Style = Style::new();
+        style.no_color_codes = true;
+
+        let mut banner: Banner = Banner::new(&style);
+        banner.width = 10;
+        banner.add_paragraph("The quick brown fox\njumps");
+
+        let expected = "┌──────────┐\r\n│The quick │\r\n│brown fox │\r\n│jumps     │\r\n└──────────┘\r\n";
+        assert_eq!(expected, banner.assemble());
+    }
+
+    /// Verifies that fixed width clips content that does not fit.
+    #[test]
+    fn test_assemble_width_fixed_clips() {
+        let mut style: Style = Style::new();
+        style.border.color = Color::White;
+
+        let mut banner: Banner = Banner::new(&style);
+        banner.width_mode = WidthMode::Fixed(3);
+        banner.add_text("Hello");
+
+        let expected = "\u{1b}[37m┌───┐\u{1b}[0m\r\n\u{1b}[37m│Hel│\u{1b}[0m\r\n\u{1b}[37m└───┘\u{1b}[0m\r\n";
+        assert_eq!(expected, banner.assemble());
+    }
+
+    /// Verifies that fit-content and range modes size the banner from its content.
+    #[test]
+    fn test_content_width_fit_and_range() {
+        let style: Style = Style::new();
+
+        let mut banner: Banner = Banner::new(&style);
+        banner.add_text("Hello");
+        banner.width_mode = WidthMode::FitContent;
+        assert_eq!(5, banner.content_width());
+
+        banner.width_mode = WidthMode::Range { min: 8, max: 20 };
+        assert_eq!(8, banner.content_width());
+
+        banner.width_mode = WidthMode::Range { min: 1, max: 3 };
+        assert_eq!(3, banner.content_width());
+    }
+
+    /// Verifies that terminal modes use the injected terminal width, leaving room for borders.
+    #[test]
+    fn test_content_width_terminal() {
+        let mut style: Style = Style::new();
+        style.no_color_codes = true;
+
+        let mut banner: Banner = Banner::new(&style);
+        banner.terminal_width = Some(10);
+        banner.add_text("A long line of text");
+
+        banner.width_mode = WidthMode::FillTerminal;
+        assert_eq!("┌────────┐\r\n│A long l│\r\n└────────┘\r\n", banner.assemble());
+
+        banner.width_mode = WidthMode::TerminalPercent(50);
+        assert_eq!(3, banner.content_width());
+    }
+
+    /// Verifies that banners sized to their width or content stop at the terminal edge.
+    #[test]
+    fn test_content_width_terminal_edge() {
+        let mut style: Style = Style::new();
+        style.no_color_codes = true;
+
+        let mut banner: Banner = Banner::new(&style);
+        banner.terminal_width = Some(8);
+        banner.padding = 1;
+        banner.add_text("A long line of text");
+        assert_eq!("┌──────┐\r\n│ A lo │\r\n└──────┘\r\n", banner.assemble());
+
+        banner.width_mode = WidthMode::FitContent;
+        assert_eq!(4, banner.content_width());
+
+        banner.width_mode = WidthMode::Fixed(10);
+        assert_eq!(10, banner.content_width());
+    }
+
+    /// Verifies that blank spacer rows are rendered as empty content rows.
+    #[test]
+    fn test_assemble_blank_rows() {
+        let mut style: Style = Style::new();
+        style.no_color_codes = true;
+
+        let mut banner: Banner = Banner::new(&style);
+        banner.width = 2;
+        banner.add_text("a");
+        banner.add_blank();
+        banner.add_text("b");
+
+        assert_eq!("┌──┐\r\n│a │\r\n│  │\r\n│b │\r\n└──┘\r\n", banner.assemble());
+    }
+
+    /// Verifies that content is aligned vertically within a minimum height.
+    #[test]
+    fn test_assemble_min_height_alignment() {
+        let mut style: Style = Style::new();
+        style.no_color_codes = true;
+
+        let mut banner: Banner = Banner::new(&style);
+        banner.width = 2;
+        banner.min_height = Some(4);
+        banner.add_text("x");
+
+        banner.vertical_alignment = VerticalAlignment::Middle;
+        assert_eq!("┌──┐\r\n│  │\r\n│x │\r\n│  │\r\n│  │\r\n└──┘\r\n", banner.assemble());
+
+        banner.vertical_alignment = VerticalAlignment::Bottom;
+        assert_eq!("┌──┐\r\n│  │\r\n│  │\r\n│  │\r\n│x │\r\n└──┘\r\n", banner.assemble());
+    }
+
+    /// Verifies that rows beyond the maximum height are elided or clipped.
+    #[test]
+    fn test_assemble_max_height_overflow() {
+        let mut style: Style = Style::new();
+        style.no_color_codes = true;
+
+        let mut banner: Banner = Banner::new(&style);
+        banner.width = 14;
+        banner.max_height = Some(2);
+        banner.add_text("one\ntwo\nthree\nfour");
+
+        let expected = "┌──────────────┐\r\n│one           │\r\n│… 3 more lines│\r\n└──────────────┘\r\n";
+        assert_eq!(expected, banner.assemble());
+
+        banner.overflow = Overflow::Clip;
+        let expected = "┌──────────────┐\r\n│one           │\r\n│two           │\r\n└──────────────┘\r\n";
+        assert_eq!(expected, banner.assemble());
+    }
+
+    /// Verifies that a title is drawn within the top border and widens the banner.
+    #[test]
+    fn test_assemble_title() {
+        let mut style: Style = Style::new();
+        style.no_color_codes = true;
+
+        let mut banner: Banner = Banner::new(&style);
+        banner.width = 2;
+        banner.set_title("Status");
+        banner.add_text("ok");
+
+        assert_eq!("┌─ Status ─┐\r\n│ok        │\r\n└──────────┘\r\n", banner.assemble());
+    }
+
+    /// Verifies that padding separates content from the borders.
+    #[test]
+    fn test_assemble_padding() {
+        let mut style: Style = Style::new();
+        style.no_color_codes = true;
+
+        let mut banner: Banner = Banner::new(&style);
+        banner.width = 2;
+        banner.padding = 1;
+        banner.add_text("ab");
+
+        assert_eq!("┌────┐\r\n│ ab │\r\n└────┘\r\n", banner.assemble());
+        assert_eq!(6, banner.outer_width());
+    }
+
+    /// Verifies that a nested banner is drawn inside the padding and widens its parent.
+    #[test]
+    fn test_assemble_nested_banner() {
+        let mut style: Style = Style::new();
+        style.no_color_codes = true;
+
+        let mut region: Banner = Banner::new(&style);
+        region.width = 2;
+        region.add_text("us-east");
+
+        let mut summary: Banner = Banner::new(&style);
+        summary.width = 2;
+        summary.padding = 1;
+        summary.add_text("Summary");
+        summary.add_banner(region);
+
+        let expected = "┌───────────┐\r\n│ Summary   │\r\n│ ┌───────┐ │\r\n│ │us-east│ │\r\n│ └───────┘ │\r\n└───────────┘\r\n";
+        assert_eq!(expected, summary.assemble());
+    }
+
+    /// Verifies that a nested banner stretches to fill a wider parent.
+    #[test]
+    fn test_assemble_nested_banner_stretches() {
+        let mut style: Style = Style::new();
+        style.no_color_codes = true;
+
+        let mut inner: Banner = Banner::new(&style);
+        inner.width = 2;
+        inner.add_text("in");
+
+        let mut outer: Banner = Banner::new(&style);
+        outer.width = 6;
+        outer.add_banner(inner);
+
+        assert_eq!("┌──────┐\r\n│┌────┐│\r\n││in  ││\r\n│└────┘│\r\n└──────┘\r\n", outer.assemble());
+    }
+
+    /// Tests that an empty banner is assembled correctly.
+    #[test]
+    fn test_assemble_empty() {
+        // Create a style
+        let mut style: Style = Style::new();
+        style.border.color = Color::White;
+
+        // Build the banner
+        let mut banner: Banner = Banner::new(&style);
+        banner.width = 4;
+
+        let expected = "\u{1b}[37m┌────┐\u{1b}[0m\r\n\u{1b}[37m└────┘\u{1b}[0m\r\n";
+        assert_eq!(expected, banner.assemble());
+    }
+
+    /// Verifies that a banner with a single text line is assembled correctly.
+    #[test]
+    fn test_assemble_simple() {
+        // Create a style
+        let mut style: Style = Style::new();
+        style.no_color_codes = true;
+
+        // Build the banner
+        let mut banner: Banner = Banner::new(&style);
+        banner.width = 16;
+        banner.add_text("Hello!");
+
+        let expected = "┌────────────────┐\r\n│Hello!          │\r\n└────────────────┘\r\n";
+        assert_eq!(expected, banner.assemble());
+    }
+
+    /// Verifies that a banner with a single text line is assembled correctly.
+    #[test]
+    fn test_assemble_simple_colored() {
+        // Create a style
+        let mut style: Style = Style::new();
+        style.border.color = Color::White;
+        style.text.content_color = Color::Red;
+
+        // Build a banner
+        let mut banner: Banner = Banner::new(&style);
+        banner.width = 16;
+
+        // Add multiple lines of text
+        banner.add_text("Hello, ");
+        banner.add_text("World!");
+
+        let expected = "\u{1b}[37m┌────────────────┐\u{1b}[0m\r\n\u{1b}[37m│\u{1b}[0m\u{1b}[31mHello, \u{1b}[0m         \u{1b}[37m│\u{1b}[0m\r\n\u{1b}[37m│\u{1b}[0m\u{1b}[31mWorld!\u{1b}[0m          \u{1b}[37m│\u{1b}[0m\r\n\u{1b}[37m└────────────────┘\u{1b}[0m\r\n";
+        assert_eq!(expected, banner.assemble());
+    }
+
+    /// Verifies the banner's cells directly, independent of how color codes are serialized.
+    #[test]
+    fn test_canvas_cells() {
+        let mut style: Style = Style::new();
+        style.border.color = Color::White;
+        style.text.content_color = Color::Red;
+
+        let mut banner: Banner = Banner::new(&style);
+        banner.width = 3;
+        banner.add_text("a\u{1b}[1;32mb");
+
+        let canvas = banner.canvas();
+        assert_eq!(vec!["┌───┐", "│ab │", "└───┘"], canvas.to_plain_rows());
+        assert_eq!(CellStyle::fg(Color::White), canvas.cell(0, 1).unwrap().style);
+        assert_eq!(CellStyle::fg(Color::Red), canvas.cell(1, 1).unwrap().style);
+        let bold = canvas.cell(2, 1).unwrap().style;
+        assert_eq!(Some(Color::Green), bold.fg);
+        assert!(bold.attributes.bold);
+        assert_eq!(CellStyle::default(), canvas.cell(3, 1).unwrap().style);
+    }
+
+    /// Verifies that pattern and wide border glyphs keep the banner rectangular.
+    #[test]
+    fn test_assemble_wide_border_glyphs() {
+        let mut style: Style = Style::new();
+        style.no_color_codes = true;
+        let glyphs = &mut style.border.glyphs;
+        for glyph in [&mut glyphs.top_left, &mut glyphs.top_right, &mut glyphs.bottom_left, &mut glyphs.bottom_right] {
+            *glyph = String::from("🟦");
+        }
+        glyphs.left = String::from("🟦");
+        glyphs.right = String::from("| ");
+        glyphs.top = String::from("🟦");
+        glyphs.bottom = String::from("-=");
+
+        let mut banner: Banner = Banner::new(&style);
+        banner.width = 3;
+        banner.add_text("abc");
+
+        assert_eq!(7, banner.outer_width());
+        let expected = "🟦🟦 🟦\r\n🟦abc| \r\n🟦-=-🟦\r\n";
+        assert_eq!(expected, banner.assemble());
+    }
+
+    /// Verifies that the shadow is drawn behind the box and counted in the banner's width.
+    #[test]
+    fn test_assemble_shadow() {
+        let mut style: Style = Style::new();
+        style.no_color_codes = true;
+        style.shadow = Some(ShadowStyle::new());
+
+        let mut banner: Banner = Banner::new(&style);
+        banner.width = 3;
+        banner.add_text("abc");
+
+        assert_eq!(6, banner.outer_width());
+        let expected = "┌───┐ \r\n│abc│░\r\n└───┘░\r\n ░░░░░\r\n";
+        assert_eq!(expected, banner.assemble());
+    }
+
+    /// Verifies that a nested banner's shadow fits inside the parent banner.
+    #[test]
+    fn test_assemble_nested_banner_shadow() {
+        let mut style: Style = Style::new();
+        style.no_color_codes = true;
+        let mut inner_style: Style = Style::new();
+        inner_style.no_color_codes = true;
+        inner_style.shadow = Some(ShadowStyle::new());
+
+        let mut inner: Banner = Banner::new(&inner_style);
+        inner.width = 2;
+        inner.add_text("ab");
+        let mut banner: Banner = Banner::new(&style);
+        banner.width = 1;
+        banner.add_banner(inner);
+
+        assert_eq!(5, banner.width);
+        let expected = "┌─────┐\r\n│┌──┐ │\r\n││ab│░│\r\n│└──┘░│\r\n│ ░░░░│\r\n└─────┘\r\n";
+        assert_eq!(expected, banner.assemble());
+    }
+
+    /// Verifies that headers in a FIGlet font span several rows and widen the banner.
+    #[test]
+    fn test_assemble_figlet_header() {
+        let mut style: Style = Style::new();
+        style.no_color_codes = true;
+        style.h1.font = Some(FigletFont::mini());
+
+        let mut banner: Banner = Banner::new(&style);
+        banner.width = 2;
+        banner.add_header("Hi", HeaderLevel::H1);
+        banner.add_text("ok");
+
+        assert_eq!(7, banner.width);
+        let expected = "┌───────┐\r\n│█ █ ▀█▀│\r\n│█▀█  █ │\r\n│▀ ▀ ▀▀▀│\r\n│ok     │\r\n└───────┘\r\n";
+        assert_eq!(expected, banner.assemble());
+    }
+
+    /// Verifies that double width headers fit within half of their row, with the borders.
+    #[test]
+    fn test_canvas_double_width_header() {
+        let mut style: Style = Style::new();
+        style.h1.line_scale = LineScale::DoubleWidth;
+
+        let mut banner: Banner = Banner::new(&style);
+        banner.width = 2;
+        banner.line_scale_support = Some(true);
+        banner.add_header("Hi", HeaderLevel::H1);
+        banner.add_text("ok");
+
+        let canvas = banner.canvas();
+        assert_eq!(8, banner.outer_width());
+        assert_eq!(LineSize::DoubleWidth, canvas.line_size(1));
+        assert_eq!(LineSize::Single, canvas.line_size(2));
+        let rows = canvas.to_ansi_rows_for(ColorSupport::TrueColor);
+        assert!(rows[1].starts_with("\u{1b}#6"));
+        let rows: Vec<String> = rows.iter().map(|row| ansi::strip(row)).collect();
+        assert_eq!(vec!["┌──────┐", "│Hi│", "│ok    │", "└──────┘"], rows);
+    }
+
+    /// Verifies that double height headers span two rows, and that the outer width is kept even.
+    #[test]
+    fn test_canvas_double_height_header() {
+        let mut style: Style = Style::new();
+        style.h1.line_scale = LineScale::DoubleHeight;
+
+        let mut banner: Banner = Banner::new(&style);
+        banner.width = 1;
+        banner.line_scale_support = Some(true);
+        banner.add_header("Hi", HeaderLevel::H1);
+        banner.set_title("T");
+
+        let canvas = banner.canvas();
+        assert_eq!(4, canvas.height());
+        assert_eq!(8, banner.outer_width());
+        assert_eq!(LineSize::DoubleHeightTop, canvas.line_size(1));
+        assert_eq!(LineSize::DoubleHeightBottom, canvas.line_size(2));
+        let rows = canvas.to_ansi_rows_for(ColorSupport::TrueColor);
+        assert!(rows[1].starts_with("\u{1b}#3"));
+        assert!(rows[2].starts_with("\u{1b}#4"));
+        assert_eq!("│Hi│", ansi::strip(&rows[1]));
+        assert_eq!("│Hi│", ansi::strip(&rows[2]));
+    }
+
+    /// Verifies that a double height header is never cut in half by the maximum height.
+    #[test]
+    fn test_canvas_double_height_header_overflow() {
+        let mut style: Style = Style::new();
+        style.no_color_codes = true;
+        style.h1.line_scale = LineScale::DoubleHeight;
+
+        let mut banner: Banner = Banner::new(&style);
+        banner.width = 14;
+        banner.max_height = Some(3);
+        banner.line_scale_support = Some(true);
+        banner.add_text("a");
+        banner.add_header("Hi", HeaderLevel::H1);
+        banner.add_text("b");
+
+        let canvas = banner.canvas();
+        assert_eq!(LineSize::Single, canvas.line_size(2));
+        let expected = vec!["┌──────────────┐", "│a             │", "│… 3 more lines│", "└──────────────┘"];
+        assert_eq!(expected, canvas.to_plain_rows());
+
+        banner.overflow = Overflow::Clip;
+        banner.max_height = Some(2);
+        let canvas = banner.canvas();
+        assert_eq!(LineSize::Single, canvas.line_size(2));
+        let expected = vec!["┌──────────────┐", "│a             │", "│Hi            │", "└──────────────┘"];
+        assert_eq!(expected, canvas.to_plain_rows());
+    }
+
+    /// Verifies that nested banners draw scaled headers at their normal size.
+    #[test]
+    fn test_canvas_nested_scaled_header() {
+        let mut style: Style = Style::new();
+        style.no_color_codes = true;
+        style.h1.line_scale = LineScale::DoubleWidth;
+
+        let mut inner: Banner = Banner::new(&style);
+        inner.width = 2;
+        inner.line_scale_support = Some(true);
+        inner.add_header("Hi", HeaderLevel::H1);
+
+        let mut banner: Banner = Banner::new(&style);
+        banner.width = 2;
+        banner.add_banner(inner);
+
+        let canvas = banner.canvas();
+        assert_eq!(LineSize::Single, canvas.line_size(2));
+        assert_eq!(vec!["┌────┐", "│┌──┐│", "││Hi││", "│└──┘│", "└────┘"], canvas.to_plain_rows());
+    }
+
+    /// Verifies that scaled headers fall back to normal text when the terminal lacks support.
+    #[test]
+    fn test_assemble_scaled_header_fallback() {
+        let mut style: Style = Style::new();
+        style.no_color_codes = true;
+        style.h1.line_scale = LineScale::DoubleHeight;
+
+        let mut banner: Banner = Banner::new(&style);
+        banner.width = 2;
+        banner.line_scale_support = Some(false);
+        banner.add_header("Hi", HeaderLevel::H1);
+
+        assert_eq!("┌──┐\r\n│Hi│\r\n└──┘\r\n", banner.assemble());
+    }
+
+    /// Verifies that transformed headers widen the banner to their transformed width.
+    #[test]
+    fn test_assemble_transformed_header() {
+        let mut style: Style = Style::new();
+        style.no_color_codes = true;
+        style.h1.transform = TextTransform::Uppercase;
+        style.h1.letter_spacing = 1;
+
+        let mut banner: Banner = Banner::new(&style);
+        banner.width = 2;
+        banner.add_header("deploy", HeaderLevel::H1);
+
+        let expected = "┌───────────┐\r\n│D E P L O Y│\r\n└───────────┘\r\n";
+        assert_eq!(expected, banner.assemble());
+    }
+
+    /// Verifies that headers are numbered by level, restarting below each higher header.
+    #[test]
+    fn test_assemble_numbered_headers() {
+        let mut style: Style = Style::new();
+        style.no_color_codes = true;
+        style.h1.numbering = Numbering::Decimal;
+        style.h2.numbering = Numbering::Decimal;
+        style.h3.numbering = Numbering::LowerAlpha;
+
+        let mut banner: Banner = Banner::new(&style);
+        banner.width = 1;
+        banner.add_header("Build", HeaderLevel::H1);
+        banner.add_header("Lint", HeaderLevel::H2);
+        banner.add_header("Test", HeaderLevel::H2);
+        banner.add_header("Unit", HeaderLevel::H3);
+        banner.add_header("Deploy", HeaderLevel::H1);
+        banner.add_header("Canary", HeaderLevel::H2);
+        banner.add_header("Notes", HeaderLevel::H4);
+
+        let rows: Vec<String> = banner
+            .canvas()
+            .to_plain_rows()
+            .iter()
+            .map(|row| row.trim_end_matches([' ', '│']).to_string())
+            .collect();
+        assert_eq!(vec!["│1. Build", "│1.1 Lint", "│1.2 Test", "│1.2.a Unit", "│2. Deploy", "│2.1 Canary", "│Notes"], rows[1..8].to_vec());
+    }
+
+    /// Verifies roman numbering and levels numbered without the levels above them.
+    #[test]
+    fn test_number_header_styles() {
+        let mut style: Style = Style::new();
+        style.h2.numbering = Numbering::UpperRoman;
+        style.h3.numbering = Numbering::Decimal;
+
+        let mut banner: Banner = Banner::new(&style);
+        assert_eq!(None, banner.number_header(HeaderLevel::H1));
+        assert_eq!(Some(String::from("1.")), banner.number_header(HeaderLevel::H3));
+        assert_eq!(Some(String::from("I.")), banner.number_header(HeaderLevel::H2));
+        assert_eq!(Some(String::from("II.")), banner.number_header(HeaderLevel::H2));
+        assert_eq!(Some(String::from("II.1")), banner.number_header(HeaderLevel::H3));
+    }
+
+    /// Verifies that unnumbered headers restart the numbering of the numbered levels below them.
+    #[test]
+    fn test_number_header_unnumbered_parent() {
+        let mut style: Style = Style::new();
+        style.h2.numbering = Numbering::Decimal;
+
+        let mut banner: Banner = Banner::new(&style);
+        assert_eq!(None, banner.number_header(HeaderLevel::H1));
+        assert_eq!(Some(String::from("1.")), banner.number_header(HeaderLevel::H2));
+        assert_eq!(Some(String::from("2.")), banner.number_header(HeaderLevel::H2));
+        assert_eq!(None, banner.number_header(HeaderLevel::H1));
+        assert_eq!(Some(String::from("1.")), banner.number_header(HeaderLevel::H2));
+    }
+
+    /// Verifies that paragraphs are sanitized before wrapping and wrap to the content width.
+    #[test]
+    fn test_assemble_paragraph_width() {
+        let mut style: Style = Style::new();
+        style.no_color_codes = true;
+
+        let mut banner: Banner = Banner::new(&style);
+        banner.width = 6;
+        banner.add_paragraph("ab\rcd ef");
+        assert_eq!(6, banner.width);
+        assert_eq!("┌──────┐\r\n│ab\\rcd│\r\n│ef    │\r\n└──────┘\r\n", banner.assemble());
+
+        let mut banner: Banner = Banner::new(&style);
+        banner.width_mode = WidthMode::FillTerminal;
+        banner.terminal_width = Some(10);
+        banner.padding = 1;
+        banner.add_paragraph("one  two three");
+        assert_eq!("┌────────┐\r\n│ one    │\r\n│ two    │\r\n│ three  │\r\n└────────┘\r\n", banner.assemble());
+    }
 }
@@ -1,3 +1,13 @@
 mod border_painter;
+mod canvas;
+mod joins;
+mod junction;
+mod line_size;
+mod shadow_painter;
 
-pub use border_painter::BorderPainter;
\ No newline at end of file
+pub use border_painter::BorderPainter;
+pub use canvas::{Attributes, Canvas, Cell, CellStyle};
+pub use joins::Joins;
+pub use junction::{Connections, LineWeight};
+pub use line_size::LineSize;
+pub use shadow_painter::ShadowPainter;
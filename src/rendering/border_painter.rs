@@ -1,9 +1,10 @@
-use crate::style::BorderStyle;
-use colored::Colorize;
+use super::{Canvas, CellStyle, Connections};
+use crate::style::{BorderPart, BorderStyle};
+use crate::text::ansi;
 
-/// Represents a border painter.alloc
+/// Represents a border painter.
 ///
-/// This is used to paint borders around banner content.
+/// This is used to paint borders around banner content into a canvas.
 pub struct BorderPainter<'a> {
     style: &'a BorderStyle,
     no_color_codes: bool,
@@ -21,196 +22,334 @@ impl BorderPainter<'_> {
     pub fn new(
         style: &BorderStyle, 
         no_color_codes: bool, 
-        width: u8) -> BorderPainter {
+        width: u8) -> BorderPainter<'_> {
         BorderPainter {
-            style: style,
-            no_color_codes: no_color_codes,
-            width: width,
+            style,
+            no_color_codes,
+            width,
         }
     }
 
-    /// Formats the border top as a colored string.
+    /// Paints a horizontal edge into a canvas, optionally with a title.
+    ///
+    /// The fill pattern is repeated between the end glyphs and trimmed to the exact width of the
+    /// border, so that the edge lines up with the sides. Nothing is painted when the width is
+    /// too small to hold an edge. Titles that do not fit within the edge are clipped.
     ///
     /// # Arguments
     ///
     /// * `self` - Reference to the border painter being operated on.
-    pub fn top(self: &Self) -> String {
+    /// * `canvas` - The canvas to paint into.
+    /// * `x` - The column of the left end of the edge.
+    /// * `y` - The row of the edge.
+    /// * `edge` - The part of the border the edge is colored as: `Top` or `Bottom`.
+    /// * `left` - The glyph at the left end of the edge, or None to omit the left border.
+    /// * `fill` - The pattern repeated along the edge.
+    /// * `title` - The title to show within the edge, if any.
+    /// * `right` - The glyph at the right end of the edge, or None to omit the right border.
+    #[allow(clippy::too_many_arguments)]
+    pub fn paint_edge(
+        self: &Self,
+        canvas: &mut Canvas,
+        x: usize,
+        y: usize,
+        edge: BorderPart,
+        left: Option<&str>,
+        fill: &str,
+        title: Option<&str>,
+        right: Option<&str>,
+    ) {
         // Guard against width values that are too small
         if self.width < 2 {
-            return String::from("");
+            return;
         }
 
-        let str: String = format!(
-            "{}{}{}",
-            self.style.glyphs.top_left,
-            (0..self.width)
-                .map(|_| self.style.glyphs.top)
-                .collect::<String>(),
-            self.style.glyphs.top_right.to_string()
-        );
-        self.colorize(str)
-    }
+        let glyphs = &self.style.glyphs;
+        let width = self.width as usize;
+        let left_width = left.map_or(0, |_| glyphs.left_width());
+        let right_width = right.map_or(0, |_| glyphs.right_width());
+        let left = left.unwrap_or("");
+        let right = right.unwrap_or("");
+        let (left_part, right_part) = match edge {
+            BorderPart::Bottom | BorderPart::BottomLeft | BorderPart::BottomRight => {
+                (BorderPart::BottomLeft, BorderPart::BottomRight)
+            }
+            _ => (BorderPart::TopLeft, BorderPart::TopRight),
+        };
 
-    /// Formats the border bottom as a colored string.
-    ///
-    /// # Arguments
-    ///
-    /// * `self` - Reference to the border painter being operated on.
-    pub fn bottom(self: &Self) -> String {
-        // Guard against width values that are too small
-        if self.width < 2 {
-            return String::from("");
-        }
+        // Paint the end glyphs, with the pattern between them
+        let end = x + left_width + width + right_width;
+        let start = x + self.paint_str(canvas, x, y, left_part, left);
+        let right_start = end - ansi::visible_width(right).min(end - start);
+        self.paint_str(canvas, start, y, edge, &repeat_to_width(fill, right_start - start));
+        self.paint_str(canvas, right_start, y, right_part, right);
 
-        let str: String = format!(
-            "{}{}{}",
-            self.style.glyphs.bottom_left,
-            (0..self.width)
-                .map(|_| self.style.glyphs.bottom)
-                .collect::<String>(),
-            self.style.glyphs.bottom_right
-        );
-        self.colorize(str)
+        // Paint the title over the pattern, leaving one column of the pattern on each side
+        let title = title.unwrap_or("");
+        let title = &title[..ansi::split_at_visible(title, width.saturating_sub(4))];
+        if !title.is_empty() {
+            self.paint_str(canvas, x + left_width + 1, y, edge, &format!(" {} ", title));
+        }
     }
 
-    /// Formats the border left-side as a colored string.
+    /// Paints a border glyph, such as a side of the border, into a canvas.
     ///
     /// # Arguments
     ///
     /// * `self` - Reference to the border painter being operated on.
-    pub fn left(self: &Self) -> String {
-        self.colorize(String::from(self.style.glyphs.left))
+    /// * `canvas` - The canvas to paint into.
+    /// * `x` - The column of the glyph.
+    /// * `y` - The row of the glyph.
+    /// * `part` - The part of the border the glyph is colored as.
+    /// * `glyph` - The glyph to paint.
+    pub fn paint_glyph(self: &Self, canvas: &mut Canvas, x: usize, y: usize, part: BorderPart, glyph: &str) {
+        self.paint_str(canvas, x, y, part, glyph);
     }
 
-    /// Formats the border right-side as a colored string.
+    /// Returns the glyph that joins edges of this border meeting in the given directions.
+    ///
+    /// The weight of the vertical and horizontal lines is read from the left and top glyphs,
+    /// and ASCII borders get ASCII junctions. Returns None if the border glyphs are not
+    /// box-drawing or ASCII border glyphs.
     ///
     /// # Arguments
     ///
     /// * `self` - Reference to the border painter being operated on.
-    pub fn right(self: &Self) -> String {
-        self.colorize(String::from(self.style.glyphs.right))
+    /// * `up` - True if an edge leaves the junction upwards.
+    /// * `down` - True if an edge leaves the junction downwards.
+    /// * `left` - True if an edge leaves the junction to the left.
+    /// * `right` - True if an edge leaves the junction to the right.
+    pub fn junction(self: &Self, up: bool, down: bool, left: bool, right: bool) -> Option<char> {
+        let glyphs = &self.style.glyphs;
+        let vertical = Connections::of(&glyphs.left)?.up?;
+        let horizontal = Connections::of(&glyphs.top)?.left?;
+        let connections = Connections {
+            up: Some(vertical).filter(|_| up),
+            down: Some(vertical).filter(|_| down),
+            left: Some(horizontal).filter(|_| left),
+            right: Some(horizontal).filter(|_| right),
+        };
+
+        if glyphs.left.is_ascii() || glyphs.top.is_ascii() {
+            connections.ascii_glyph()
+        } else {
+            connections.glyph()
+        }
     }
 
-    /// Applies color codes to the output (if not using monochrome).
+    /// Paints text as part of the border, coloring each cell by its position in the canvas.
+    ///
+    /// Returns the number of columns the text occupies.
     ///
     /// # Arguments
     ///
     /// * `self` - Reference to the border painter being operated on.
-    /// * `str`  - The string to colorize.
-    fn colorize(self: &Self, str: String) -> String {
+    /// * `canvas` - The canvas to paint into, covering the whole bordered area.
+    /// * `x` - The column of the first character.
+    /// * `y` - The row.
+    /// * `part` - The part of the border the text is colored as.
+    /// * `text` - The text to paint.
+    fn paint_str(self: &Self, canvas: &mut Canvas, x: usize, y: usize, part: BorderPart, text: &str) -> usize {
+        let columns = canvas.put_str(x, y, text, CellStyle::default());
         if !self.no_color_codes {
-            str.color(self.style.color.to_string()).to_string()
-        } else {
-            str
+            let (width, height) = (canvas.width(), canvas.height());
+            for column in x..x + columns {
+                let color = self.style.color_at(part, column, y, width, height);
+                canvas.set_style(column, y, CellStyle::fg(color));
+            }
+        }
+
+        columns
+    }
+}
+
+/// Repeats a pattern to fill the given number of columns exactly.
+///
+/// A wide character that would overrun the last column is replaced with a space.
+///
+/// # Arguments
+///
+/// * `pattern` - The pattern to repeat.
+/// * `columns` - The number of columns to fill.
+pub(crate) fn repeat_to_width(pattern: &str, columns: usize) -> String {
+    let mut result = String::new();
+    let mut used = 0;
+    if ansi::visible_width(pattern) > 0 {
+        for c in pattern.chars().cycle() {
+            let width = ansi::char_width(c);
+            if used + width > columns {
+                break;
+            }
+            result.push(c);
+            used += width;
         }
     }
+    result.extend((used..columns).map(|_| ' '));
+
+    result
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::style::{BorderGlyphs, Color};
+    use crate::style::{Color, Gradient, GradientDirection};
 
     /// Creates a default border style for use in unit tests.
     fn default_border_style() -> BorderStyle {
-        BorderStyle {
-            color: Color::White,
-            is_visible: true,
-            glyphs: BorderGlyphs::new(),
-        }
+        BorderStyle::new()
+    }
+
+    /// Paints an edge into a single row canvas and serializes it.
+    fn paint(
+        painter: &BorderPainter,
+        left: Option<&str>,
+        fill: &str,
+        title: Option<&str>,
+        right: Option<&str>,
+    ) -> String {
+        let width = painter.width as usize + left.iter().count() + right.iter().count();
+        let mut canvas = Canvas::new(width, 1);
+        painter.paint_edge(&mut canvas, 0, 0, BorderPart::Top, left, fill, title, right);
+        canvas.to_ansi_rows().concat()
     }
 
     /// Verifies that the painter renders a basic top border line.
     #[test]
-    fn test_fmt_top_basic() {
+    fn test_paint_top_basic() {
         let style = default_border_style();
         let painter: BorderPainter = BorderPainter::new(&style, true, 4);
-        let expected = format!("{}", "┌────┐");
-        assert_eq!(expected, painter.top());
+        let expected = "┌────┐";
+        assert_eq!(expected, paint(&painter, Some("┌"), "─", None, Some("┐")));
     }
 
-    /// Verifies that the top painter handles a width that is too small.
+    /// Verifies that the painter paints nothing when the width is too small.
     #[test]
-    fn test_fmt_top_zero_width() {
+    fn test_paint_top_zero_width() {
         let style = default_border_style();
         let painter: BorderPainter = BorderPainter::new(&style, true, 0);
-        let expected = format!("{}", "");
-        assert_eq!(expected, painter.top());
+        let expected = "  ";
+        assert_eq!(expected, paint(&painter, Some("┌"), "─", None, Some("┐")));
     }
 
     /// Verifies that the painter renders a top border line that includes color codes.
     #[test]
-    fn test_fmt_top_colored() {
+    fn test_paint_top_colored() {
         let mut style = default_border_style();
         style.color = Color::Red;
         let painter: BorderPainter = BorderPainter::new(&style, false, 4);
-        let expected = format!("{}", "\u{1b}[31m┌────┐\u{1b}[0m");
-        assert_eq!(expected, painter.top());
+        let expected = "\u{1b}[31m┌────┐\u{1b}[0m";
+        assert_eq!(expected, paint(&painter, Some("┌"), "─", None, Some("┐")));
     }
 
-    /// Verifies that the painter renders a basic bottom border line.
+    /// Verifies that border cells carry the border color, independent of the output format.
     #[test]
-    fn test_fmt_bottom_basic() {
-        let style = default_border_style();
-        let painter: BorderPainter = BorderPainter::new(&style, true, 4);
-        let expected = format!("{}", "└────┘");
-        assert_eq!(expected, painter.bottom());
-    }
+    fn test_paint_cells() {
+        let mut style = default_border_style();
+        style.color = Color::Red;
+        let painter: BorderPainter = BorderPainter::new(&style, false, 2);
+        let mut canvas = Canvas::new(4, 2);
+        painter.paint_edge(&mut canvas, 0, 0, BorderPart::Top, Some("└"), "─", None, Some("┘"));
+        painter.paint_glyph(&mut canvas, 3, 1, BorderPart::Right, "│");
 
-    /// Verifies that the bottom painter handles a width that is too small.
-    #[test]
-    fn test_fmt_bottom_zero_width() {
-        let style = default_border_style();
-        let painter: BorderPainter = BorderPainter::new(&style, true, 0);
-        let expected = format!("{}", "");
-        assert_eq!(expected, painter.bottom());
+        assert_eq!("└", canvas.cell(0, 0).unwrap().glyph);
+        assert_eq!(CellStyle::fg(Color::Red), canvas.cell(0, 0).unwrap().style);
+        assert_eq!(CellStyle::fg(Color::Red), canvas.cell(3, 1).unwrap().style);
+        assert_eq!(CellStyle::default(), canvas.cell(0, 1).unwrap().style);
     }
 
-    /// Verifies that the painter renders a top border line that includes color codes.
+    /// Verifies that monochrome painters leave cells unstyled.
     #[test]
-    fn test_fmt_bottom_colored() {
+    fn test_paint_monochrome() {
         let mut style = default_border_style();
         style.color = Color::Red;
-        let painter: BorderPainter = BorderPainter::new(&style, false, 4);
-        let expected = format!("{}", "\u{1b}[31m└────┘\u{1b}[0m");
-        assert_eq!(expected, painter.bottom());
+        let painter: BorderPainter = BorderPainter::new(&style, true, 4);
+        let mut canvas = Canvas::new(1, 1);
+        painter.paint_glyph(&mut canvas, 0, 0, BorderPart::Left, "│");
+
+        assert_eq!(vec!["│"], canvas.to_ansi_rows());
+        assert_eq!(CellStyle::default(), canvas.cell(0, 0).unwrap().style);
     }
 
-    /// Verifies painting a basic left border.
+    /// Verifies painting an edge with junctions and an omitted end.
     #[test]
-    fn test_fmt_left_basic() {
+    fn test_paint_edge_junctions() {
         let style = default_border_style();
-        let painter: BorderPainter = BorderPainter::new(&style, true, 4);
-        let expected = format!("{}", "│");
-        assert_eq!(expected, painter.left());
+        let painter: BorderPainter = BorderPainter::new(&style, true, 3);
+        assert_eq!("├───┤", paint(&painter, Some("├"), "─", None, Some("┤")));
+        assert_eq!("───┬", paint(&painter, None, "─", None, Some("┬")));
+    }
+
+    /// Verifies painting an edge with a title, including clipping a title that is too long.
+    #[test]
+    fn test_paint_titled_edge() {
+        let style = default_border_style();
+        let painter: BorderPainter = BorderPainter::new(&style, true, 8);
+        assert_eq!("┌─ Tab ──┐", paint(&painter, Some("┌"), "─", Some("Tab"), Some("┐")));
+        assert_eq!("├─ Long ─┤", paint(&painter, Some("├"), "─", Some("Longer"), Some("┤")));
     }
 
-    /// Verifies painting a basic left border.
+    /// Verifies that patterns repeat to the exact width and multi-character ends are kept whole.
     #[test]
-    fn test_fmt_left_colored() {
+    fn test_paint_patterns() {
         let mut style = default_border_style();
-        style.color = Color::Red;
-        let painter: BorderPainter = BorderPainter::new(&style, false, 4);
-        let expected = format!("{}", "\u{1b}[31m│\u{1b}[0m");
-        assert_eq!(expected, painter.left());
+        style.glyphs.top_left = String::from("<<");
+        style.glyphs.top_right = String::from(">>");
+        let painter: BorderPainter = BorderPainter::new(&style, true, 5);
+        let mut canvas = Canvas::new(9, 1);
+        painter.paint_edge(&mut canvas, 0, 0, BorderPart::Top, Some("<<"), "-=", None, Some(">>"));
+        assert_eq!(vec!["<<-=-=->>"], canvas.to_plain_rows());
+
+        let mut canvas = Canvas::new(9, 1);
+        painter.paint_edge(&mut canvas, 0, 0, BorderPart::Top, Some("<<"), "~*~", Some("Hi"), Some(">>"));
+        assert_eq!(vec!["<<~ H *>>"], canvas.to_plain_rows());
     }
 
-    /// Verifies painting a basic right border.
+    /// Verifies that wide pattern glyphs never overrun the edge.
     #[test]
-    fn test_fmt_right_default_monochrome() {
+    fn test_paint_wide_pattern() {
         let style = default_border_style();
-        let painter: BorderPainter = BorderPainter::new(&style, true, 4);
-        let expected = format!("{}", "│");
-        assert_eq!(expected, painter.right());
+        let painter: BorderPainter = BorderPainter::new(&style, true, 5);
+        let mut canvas = Canvas::new(7, 1);
+        painter.paint_edge(&mut canvas, 0, 0, BorderPart::Top, Some("┌"), "🟦", None, Some("┐"));
+        assert_eq!(vec!["┌🟦🟦 ┐"], canvas.to_plain_rows());
     }
 
-    /// Verifies painting a basic right border.
+    /// Verifies that side and corner colors take precedence over the border color.
     #[test]
-    fn test_fmt_right_colored() {
+    fn test_paint_part_colors() {
         let mut style = default_border_style();
-        style.color = Color::Red;
+        style.colors.top = Some(Color::Red);
+        style.colors.top_right = Some(Color::Blue);
+        style.colors.left = Some(Color::Green);
+        let painter: BorderPainter = BorderPainter::new(&style, false, 2);
+        let mut canvas = Canvas::new(4, 2);
+        painter.paint_edge(&mut canvas, 0, 0, BorderPart::Top, Some("┌"), "─", None, Some("┐"));
+        painter.paint_glyph(&mut canvas, 0, 1, BorderPart::Left, "│");
+        painter.paint_glyph(&mut canvas, 3, 1, BorderPart::Right, "│");
+
+        let fg = |x, y| canvas.cell(x, y).unwrap().style.fg;
+        assert_eq!(Some(Color::Red), fg(0, 0));
+        assert_eq!(Some(Color::Red), fg(1, 0));
+        assert_eq!(Some(Color::Blue), fg(3, 0));
+        assert_eq!(Some(Color::Green), fg(0, 1));
+        assert_eq!(Some(Color::White), fg(3, 1));
+    }
+
+    /// Verifies that a gradient is interpolated per cell across the canvas.
+    #[test]
+    fn test_paint_gradient() {
+        let mut style = default_border_style();
+        style.gradient = Some(Gradient::new(
+            GradientDirection::Horizontal,
+            &[Color::Rgb(0, 0, 0), Color::Rgb(0, 0, 255)],
+        ));
         let painter: BorderPainter = BorderPainter::new(&style, false, 4);
-        let expected = format!("{}", "\u{1b}[31m│\u{1b}[0m");
-        assert_eq!(expected, painter.right());
+        let mut canvas = Canvas::new(6, 1);
+        painter.paint_edge(&mut canvas, 0, 0, BorderPart::Top, Some("┌"), "─", None, Some("┐"));
+
+        let fg = |x| canvas.cell(x, 0).unwrap().style.fg;
+        assert_eq!(Some(Color::Rgb(0, 0, 0)), fg(0));
+        assert_eq!(Some(Color::Rgb(0, 0, 102)), fg(2));
+        assert_eq!(Some(Color::Rgb(0, 0, 255)), fg(5));
     }
 }
@@ -0,0 +1,696 @@
+use super::{junction, LineSize};
+use crate::style::{Color, ColorSupport};
+use crate::terminal;
+use crate::text::ansi::{self, Token};
+
+/// Describes the text attributes of a cell.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Attributes {
+    pub bold: bool,
+    pub dim: bool,
+    pub italic: bool,
+    pub underline: bool,
+}
+
+/// Describes the colors and attributes of a cell.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CellStyle {
+    pub fg: Option<Color>,
+    pub bg: Option<Color>,
+    pub attributes: Attributes,
+}
+
+impl CellStyle {
+    /// Returns a style with the given foreground color.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The foreground color.
+    pub fn fg(color: Color) -> CellStyle {
+        CellStyle {
+            fg: Some(color),
+            ..CellStyle::default()
+        }
+    }
+
+    /// Returns the SGR sequence that applies this style, or an empty string for the default style.
+    ///
+    /// Codes are written in the same order as the `colored` crate: attributes, background,
+    /// then foreground.
+    pub fn sgr(self: &Self) -> String {
+        let mut codes: Vec<String> = Vec::new();
+        let attributes = [
+            (self.attributes.bold, "1"),
+            (self.attributes.dim, "2"),
+            (self.attributes.italic, "3"),
+            (self.attributes.underline, "4"),
+        ];
+        codes.extend(attributes.iter().filter(|(set, _)| *set).map(|(_, code)| code.to_string()));
+        codes.extend(self.bg.map(|color| color.bg_code()));
+        codes.extend(self.fg.map(|color| color.fg_code()));
+
+        if codes.is_empty() {
+            String::new()
+        } else {
+            format!("\u{1b}[{}m", codes.join(";"))
+        }
+    }
+
+    /// Returns this style with its colors replaced by the closest colors the terminal supports.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The style to degrade.
+    /// * `support` - The range of colors the terminal can display.
+    pub fn degrade(self: &Self, support: ColorSupport) -> CellStyle {
+        CellStyle {
+            fg: self.fg.map(|color| color.degrade(support)),
+            bg: self.bg.map(|color| color.degrade(support)),
+            attributes: self.attributes,
+        }
+    }
+
+    /// Applies the parameters of an SGR sequence (e.g. `ESC[1;31m`) to this style.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The style to update.
+    /// * `sgr` - The SGR sequence.
+    /// * `base` - The style restored by a reset.
+    fn apply_sgr(self: &mut Self, sgr: &str, base: CellStyle) {
+        let params: Vec<u8> = sgr[2..sgr.len() - 1]
+            .split(';')
+            .map(|param| param.parse::<u8>().unwrap_or(0))
+            .collect();
+
+        let mut i = 0;
+        while i < params.len() {
+            match params[i] {
+                0 => *self = base,
+                1 => self.attributes.bold = true,
+                2 => self.attributes.dim = true,
+                3 => self.attributes.italic = true,
+                4 => self.attributes.underline = true,
+                22 => {
+                    self.attributes.bold = false;
+                    self.attributes.dim = false;
+                }
+                23 => self.attributes.italic = false,
+                24 => self.attributes.underline = false,
+                code @ 30..=37 => self.fg = Some(Color::from_ansi_index(code - 30)),
+                39 => self.fg = base.fg,
+                code @ 40..=47 => self.bg = Some(Color::from_ansi_index(code - 40)),
+                49 => self.bg = base.bg,
+                code @ 90..=97 => self.fg = Some(Color::Fixed(code - 90 + 8)),
+                code @ 100..=107 => self.bg = Some(Color::Fixed(code - 100 + 8)),
+                code @ (38 | 48) => {
+                    let (color, used) = match params.get(i + 1) {
+                        Some(5) => (params.get(i + 2).map(|index| Color::Fixed(*index)), 2),
+                        Some(2) if i + 4 < params.len() => {
+                            (Some(Color::Rgb(params[i + 2], params[i + 3], params[i + 4])), 4)
+                        }
+                        _ => (None, 0),
+                    };
+                    if code == 38 {
+                        self.fg = color.or(self.fg);
+                    } else {
+                        self.bg = color.or(self.bg);
+                    }
+                    i += used;
+                }
+                _ => {}
+            }
+            i += 1;
+        }
+    }
+}
+
+/// Describes a single character cell of a canvas.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cell {
+    pub glyph: String,
+    pub style: CellStyle,
+}
+
+impl Cell {
+    /// Returns an unstyled space.
+    pub fn blank() -> Cell {
+        Cell {
+            glyph: String::from(" "),
+            style: CellStyle::default(),
+        }
+    }
+}
+
+/// A two dimensional grid of styled cells that banners are drawn into.
+///
+/// Drawing outside the canvas is clipped. Once drawing is complete the canvas is serialized
+/// to rows of text, with or without ANSI color codes.
+///
+/// Rows can be given a DEC line size. Such rows are laid out as normal in the canvas, so that
+/// plain text output stays readable, and are folded to half their width when serialized with
+/// escape sequences.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Canvas {
+    width: usize,
+    height: usize,
+    cells: Vec<Cell>,
+    /// The size of each row, and the number of columns kept at its right edge when folded.
+    line_sizes: Vec<(LineSize, usize)>,
+}
+
+impl Canvas {
+    /// Creates a new canvas filled with blank cells.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The number of columns.
+    /// * `height` - The number of rows.
+    pub fn new(width: usize, height: usize) -> Canvas {
+        Canvas {
+            width,
+            height,
+            cells: vec![Cell::blank(); width * height],
+            line_sizes: vec![(LineSize::Single, 0); height],
+        }
+    }
+
+    /// Returns the number of columns in the canvas.
+    pub fn width(self: &Self) -> usize {
+        self.width
+    }
+
+    /// Returns the number of rows in the canvas.
+    pub fn height(self: &Self) -> usize {
+        self.height
+    }
+
+    /// Returns the cell at the given position, if it is within the canvas.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The canvas to read from.
+    /// * `x` - The column.
+    /// * `y` - The row.
+    pub fn cell(self: &Self, x: usize, y: usize) -> Option<&Cell> {
+        if x < self.width && y < self.height {
+            Some(&self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// Sets the cell at the given position. Positions outside the canvas are ignored.
+    ///
+    /// A wide glyph (such as most emoji) occupies this cell and the next, which is left empty
+    /// as a continuation of it. A wide glyph that does not fit in the last column is replaced
+    /// with a space, and a wide glyph that is partly overwritten is replaced with spaces.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The canvas to draw into.
+    /// * `x` - The column.
+    /// * `y` - The row.
+    /// * `glyph` - The text shown in the cell, or an empty string to continue a wide glyph.
+    /// * `style` - The style of the cell.
+    pub fn set(self: &mut Self, x: usize, y: usize, glyph: &str, style: CellStyle) {
+        if x >= self.width || y >= self.height {
+            return;
+        }
+
+        let index = y * self.width + x;
+        if glyph.is_empty() {
+            // Continuations are only kept if they follow a wide glyph
+            let follows_wide = x > 0 && ansi::visible_width(&self.cells[index - 1].glyph) > 1;
+            if !follows_wide {
+                return self.set(x, y, " ", style);
+            }
+            self.clear_wide(x, y);
+            self.cells[index] = Cell { glyph: String::new(), style };
+            return;
+        }
+
+        self.clear_wide(x, y);
+        let is_wide = ansi::visible_width(glyph) > 1;
+        if is_wide && x + 1 >= self.width {
+            self.cells[index] = Cell { glyph: String::from(" "), style };
+            return;
+        }
+
+        self.cells[index] = Cell { glyph: glyph.to_string(), style };
+        if is_wide {
+            self.clear_wide(x + 1, y);
+            self.cells[index + 1] = Cell { glyph: String::new(), style };
+        }
+    }
+
+    /// Writes text into a row, one character per cell (two for wide characters).
+    ///
+    /// Zero width characters, such as combining marks, are added to the previous cell. Returns
+    /// the number of columns the text occupies, including any that were clipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The canvas to draw into.
+    /// * `x` - The column of the first character.
+    /// * `y` - The row.
+    /// * `text` - The text to write. It must not contain escape sequences.
+    /// * `style` - The style of the written cells.
+    pub fn put_str(self: &mut Self, x: usize, y: usize, text: &str, style: CellStyle) -> usize {
+        let mut column = x;
+        let mut buffer = [0; 4];
+        for c in text.chars() {
+            let width = ansi::char_width(c);
+            if width == 0 {
+                if column > x && column <= self.width && y < self.height {
+                    let mut previous = y * self.width + column - 1;
+                    if self.cells[previous].glyph.is_empty() && column > 1 {
+                        previous -= 1;
+                    }
+                    self.cells[previous].glyph.push(c);
+                }
+                continue;
+            }
+
+            self.set(column, y, c.encode_utf8(&mut buffer), style);
+            column += width;
+        }
+
+        column - x
+    }
+
+    /// Replaces the halves of a wide glyph with spaces if either half is at the given position.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The canvas to update.
+    /// * `x` - The column about to be overwritten.
+    /// * `y` - The row.
+    fn clear_wide(self: &mut Self, x: usize, y: usize) {
+        let index = y * self.width + x;
+        if self.cells[index].glyph.is_empty() && x > 0 {
+            self.cells[index - 1].glyph = String::from(" ");
+        }
+        if x + 1 < self.width && self.cells[index + 1].glyph.is_empty() {
+            self.cells[index + 1].glyph = String::from(" ");
+        }
+    }
+
+    /// Writes text that may contain ANSI color codes into a row.
+    ///
+    /// Color codes in the text are applied on top of the given style, and a reset restores the
+    /// given style. Other escape sequences are ignored. Returns the number of columns the
+    /// visible text occupies.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The canvas to draw into.
+    /// * `x` - The column of the first character.
+    /// * `y` - The row.
+    /// * `text` - The text to write.
+    /// * `style` - The style of the written cells.
+    pub fn put_ansi(self: &mut Self, x: usize, y: usize, text: &str, style: CellStyle) -> usize {
+        let mut column = x;
+        let mut current = style;
+        for token in ansi::tokenize(text) {
+            match token {
+                Token::Text(t) => column += self.put_str(column, y, t, current),
+                Token::Sgr(sgr) => current.apply_sgr(sgr, style),
+                Token::Other(_) => {}
+            }
+        }
+
+        column - x
+    }
+
+    /// Copies another canvas into this canvas. Cells that fall outside this canvas are clipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The canvas to draw into.
+    /// * `other` - The canvas to copy.
+    /// * `x` - The column to place the left edge of the other canvas at.
+    /// * `y` - The row to place the top edge of the other canvas at.
+    pub fn blit(self: &mut Self, other: &Canvas, x: usize, y: usize) {
+        // Line sizes apply to whole rows, so they are only kept when the rows are copied whole
+        if x == 0 && other.width == self.width {
+            for (row, (size, edge)) in other.line_sizes.iter().enumerate() {
+                self.set_line_size(y + row, *size, *edge);
+            }
+        }
+
+        for row in 0..other.height {
+            for column in 0..other.width {
+                let cell = &other.cells[row * other.width + column];
+                self.set(x + column, y + row, &cell.glyph, cell.style);
+            }
+        }
+    }
+
+    /// Stamps another canvas on top of this canvas.
+    ///
+    /// Unlike `blit`, the position may be negative, and where a box-drawing glyph of the other
+    /// canvas lands on a box-drawing glyph of this canvas the two are merged into a junction
+    /// (e.g. `┼` where edges cross). Cells that fall outside this canvas are clipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The canvas to draw into.
+    /// * `other` - The canvas to stamp.
+    /// * `x` - The column to place the left edge of the other canvas at.
+    /// * `y` - The row to place the top edge of the other canvas at.
+    pub fn overlay(self: &mut Self, other: &Canvas, x: isize, y: isize) {
+        for row in 0..other.height {
+            for column in 0..other.width {
+                let (tx, ty) = (x + column as isize, y + row as isize);
+                if tx < 0 || ty < 0 {
+                    continue;
+                }
+                let (tx, ty) = (tx as usize, ty as usize);
+
+                let cell = &other.cells[row * other.width + column];
+                let merged = self.cell(tx, ty).and_then(|below| junction::merge(&below.glyph, &cell.glyph));
+                match merged {
+                    Some(glyph) => self.set(tx, ty, glyph.encode_utf8(&mut [0; 4]), cell.style),
+                    None => self.set(tx, ty, &cell.glyph, cell.style),
+                }
+            }
+        }
+    }
+
+    /// Joins the border glyph at the given position to the lines of its neighbouring cells,
+    /// where two boxes share an edge. Cells that are not border glyphs are left as they are.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The canvas to update.
+    /// * `x` - The column.
+    /// * `y` - The row.
+    pub fn connect(self: &mut Self, x: usize, y: usize) {
+        let glyph = |x: Option<usize>, y: Option<usize>| match (x, y) {
+            (Some(x), Some(y)) => self.cell(x, y).map(|cell| cell.glyph.as_str()),
+            _ => None,
+        };
+        let neighbours = [
+            glyph(Some(x), y.checked_sub(1)),
+            glyph(Some(x), Some(y + 1)),
+            glyph(x.checked_sub(1), Some(y)),
+            glyph(Some(x + 1), Some(y)),
+        ];
+        let connected = match self.cell(x, y) {
+            Some(cell) => junction::connect(&cell.glyph, neighbours).map(|glyph| (glyph, cell.style)),
+            None => None,
+        };
+        if let Some((glyph, style)) = connected {
+            self.set(x, y, glyph.encode_utf8(&mut [0; 4]), style);
+        }
+    }
+
+    /// Sets the style of the cell at the given position, keeping its glyph.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The canvas to update.
+    /// * `x` - The column.
+    /// * `y` - The row.
+    /// * `style` - The new style of the cell.
+    pub fn set_style(self: &mut Self, x: usize, y: usize, style: CellStyle) {
+        if x < self.width && y < self.height {
+            self.cells[y * self.width + x].style = style;
+        }
+    }
+
+    /// Sets the DEC line size of a row.
+    ///
+    /// When a row is drawn at double width only half of its columns fit on the terminal, so the
+    /// row is folded: it keeps the columns from its left edge, followed by the given number of
+    /// columns from its right edge (e.g. a right border).
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The canvas to update.
+    /// * `y` - The row.
+    /// * `size` - The line size.
+    /// * `edge` - The number of columns at the right of the row kept at the right edge.
+    pub fn set_line_size(self: &mut Self, y: usize, size: LineSize, edge: usize) {
+        if y < self.height {
+            self.line_sizes[y] = (size, edge);
+        }
+    }
+
+    /// Returns the DEC line size of a row.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The canvas to read from.
+    /// * `y` - The row.
+    pub fn line_size(self: &Self, y: usize) -> LineSize {
+        self.line_sizes.get(y).map_or(LineSize::Single, |(size, _)| *size)
+    }
+
+    /// Removes the colors and attributes from every cell.
+    pub fn clear_styles(self: &mut Self) {
+        for cell in self.cells.iter_mut() {
+            cell.style = CellStyle::default();
+        }
+    }
+
+    /// Serializes the canvas to rows of plain text.
+    ///
+    /// Line sizes are ignored, so rows are written at their normal size.
+    pub fn to_plain_rows(self: &Self) -> Vec<String> {
+        (0..self.height)
+            .map(|y| self.row(y).iter().map(|cell| cell.glyph.as_str()).collect())
+            .collect()
+    }
+
+    /// Serializes the canvas to rows of text with ANSI color codes.
+    ///
+    /// Adjacent cells with the same style are written as a single run, and every styled run
+    /// ends with a reset. Colors the terminal cannot display are replaced with the closest
+    /// color it can. Color codes are omitted when colored output is disabled for the process
+    /// (e.g. when output is not a terminal), and so are line sizes.
+    pub fn to_ansi_rows(self: &Self) -> Vec<String> {
+        if !colored::control::SHOULD_COLORIZE.should_colorize() {
+            return self.to_plain_rows();
+        }
+
+        self.to_ansi_rows_for(terminal::color_support())
+    }
+
+    /// Serializes the canvas to rows of text with ANSI color codes for a terminal with the given
+    /// color support.
+    ///
+    /// Rows with a line size start with its escape sequence and are folded to half their width.
+    /// The bottom half of a double height row repeats the content of the row above it.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The canvas to serialize.
+    /// * `support` - The range of colors the terminal can display.
+    pub fn to_ansi_rows_for(self: &Self, support: ColorSupport) -> Vec<String> {
+        (0..self.height)
+            .map(|y| {
+                let row = self.folded_row(y);
+                let row = row.as_slice();
+                let styles: Vec<CellStyle> = row.iter().map(|cell| cell.style.degrade(support)).collect();
+                let mut result = match self.line_size(y) {
+                    LineSize::Single => String::new(),
+                    size => String::from(size.escape()),
+                };
+                let mut start = 0;
+                while start < row.len() {
+                    let style = styles[start];
+                    let end = styles[start..]
+                        .iter()
+                        .position(|other| *other != style)
+                        .map_or(row.len(), |offset| start + offset);
+                    let text: String = row[start..end].iter().map(|cell| cell.glyph.as_str()).collect();
+
+                    let sgr = style.sgr();
+                    if sgr.is_empty() {
+                        result.push_str(&text);
+                    } else {
+                        result.push_str(&sgr);
+                        result.push_str(&text);
+                        result.push_str(ansi::RESET);
+                    }
+                    start = end;
+                }
+                result
+            })
+            .collect()
+    }
+
+    /// Returns the cells of a row as the terminal shows them, folded to half the width for rows
+    /// drawn at double width.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The canvas to read from.
+    /// * `y` - The row.
+    fn folded_row(self: &Self, y: usize) -> Vec<Cell> {
+        let (size, edge) = self.line_sizes[y];
+        let source = match size {
+            LineSize::DoubleHeightBottom if y > 0 => self.row(y - 1),
+            _ => self.row(y),
+        };
+        if size == LineSize::Single {
+            return source.to_vec();
+        }
+
+        let half = self.width / 2;
+        let edge = edge.min(half);
+        let mut cells = source[..half - edge].to_vec();
+        cells.extend_from_slice(&source[self.width - edge..]);
+        cells
+    }
+
+    /// Returns the cells of a row.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The canvas to read from.
+    /// * `y` - The row.
+    fn row(self: &Self, y: usize) -> &[Cell] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verifies that text is written into cells and clipped at the edge of the canvas.
+    #[test]
+    fn test_put_str_clips() {
+        let mut canvas = Canvas::new(3, 1);
+        let written = canvas.put_str(1, 0, "abc", CellStyle::fg(Color::Red));
+
+        assert_eq!(3, written);
+        assert_eq!(vec![" ab"], canvas.to_plain_rows());
+        assert_eq!(Some(Color::Red), canvas.cell(2, 0).unwrap().style.fg);
+    }
+
+    /// Verifies that color codes in text are parsed into cell styles and resets restore the base style.
+    #[test]
+    fn test_put_ansi_styles() {
+        let mut canvas = Canvas::new(4, 1);
+        let base = CellStyle::fg(Color::Red);
+        canvas.put_ansi(0, 0, "a\u{1b}[1;32mb\u{1b}[0mc\u{1b}[38;5;208md", base);
+
+        assert_eq!(base, canvas.cell(0, 0).unwrap().style);
+        assert_eq!(Some(Color::Green), canvas.cell(1, 0).unwrap().style.fg);
+        assert!(canvas.cell(1, 0).unwrap().style.attributes.bold);
+        assert_eq!(base, canvas.cell(2, 0).unwrap().style);
+        assert_eq!(Some(Color::Fixed(208)), canvas.cell(3, 0).unwrap().style.fg);
+        assert_eq!(vec!["abcd"], canvas.to_plain_rows());
+    }
+
+    /// Verifies that runs of cells with the same style share one color sequence.
+    #[test]
+    fn test_to_ansi_rows_runs() {
+        let mut canvas = Canvas::new(5, 1);
+        canvas.put_str(0, 0, "ab", CellStyle::fg(Color::Red));
+        canvas.put_str(3, 0, "c", CellStyle::fg(Color::Blue));
+
+        assert_eq!(vec!["\u{1b}[31mab\u{1b}[0m \u{1b}[34mc\u{1b}[0m "], canvas.to_ansi_rows());
+    }
+
+    /// Verifies that SGR sequences are written in the same order as the colored crate.
+    #[test]
+    fn test_cell_style_sgr() {
+        let mut style = CellStyle::fg(Color::Blue);
+        style.bg = Some(Color::Blue);
+        style.attributes.bold = true;
+
+        assert_eq!("\u{1b}[1;44;34m", style.sgr());
+        assert_eq!("", CellStyle::default().sgr());
+    }
+
+    /// Verifies that one canvas can be copied into another.
+    #[test]
+    fn test_blit() {
+        let mut inner = Canvas::new(2, 2);
+        inner.put_str(0, 0, "ab", CellStyle::default());
+        inner.put_str(0, 1, "cd", CellStyle::default());
+        let mut outer = Canvas::new(3, 2);
+        outer.blit(&inner, 2, 1);
+
+        assert_eq!(vec!["   ", "  a"], outer.to_plain_rows());
+    }
+
+    /// Verifies that overlaid borders merge into junctions and are clipped at the edges.
+    #[test]
+    fn test_overlay() {
+        let mut base = Canvas::new(3, 3);
+        base.put_str(0, 0, "┌─┐", CellStyle::default());
+        base.put_str(0, 1, "│ │", CellStyle::default());
+        base.put_str(0, 2, "└─┘", CellStyle::default());
+        let mut badge = Canvas::new(2, 2);
+        badge.put_str(0, 0, "┌─", CellStyle::default());
+        badge.put_str(0, 1, "│x", CellStyle::default());
+
+        base.overlay(&badge, 2, 1);
+        base.overlay(&badge, -1, -1);
+        assert_eq!(vec!["x─┐", "│ ├", "└─┤"], base.to_plain_rows());
+    }
+
+    /// Verifies that wide glyphs occupy two cells and are never left half drawn.
+    #[test]
+    fn test_wide_glyphs() {
+        let mut canvas = Canvas::new(4, 1);
+        let written = canvas.put_str(0, 0, "日e\u{301}🎉", CellStyle::default());
+
+        assert_eq!(5, written);
+        assert_eq!(vec!["日e\u{301} "], canvas.to_plain_rows());
+        assert_eq!("", canvas.cell(1, 0).unwrap().glyph);
+
+        canvas.put_str(1, 0, "x", CellStyle::default());
+        assert_eq!(vec![" xe\u{301} "], canvas.to_plain_rows());
+    }
+
+    /// Verifies that truecolor cells are degraded for terminals with fewer colors.
+    #[test]
+    fn test_to_ansi_rows_degrades() {
+        let mut canvas = Canvas::new(2, 1);
+        canvas.put_str(0, 0, "a", CellStyle::fg(Color::Rgb(255, 135, 0)));
+        canvas.put_str(1, 0, "b", CellStyle::fg(Color::Rgb(250, 130, 5)));
+
+        assert_eq!(vec!["\u{1b}[38;2;255;135;0ma\u{1b}[0m\u{1b}[38;2;250;130;5mb\u{1b}[0m"], canvas.to_ansi_rows_for(ColorSupport::TrueColor));
+        assert_eq!(vec!["\u{1b}[38;5;208mab\u{1b}[0m"], canvas.to_ansi_rows_for(ColorSupport::Ansi256));
+        assert_eq!(vec!["\u{1b}[33mab\u{1b}[0m"], canvas.to_ansi_rows_for(ColorSupport::Ansi16));
+    }
+
+    /// Verifies that rows with a line size are prefixed with their escape sequence and folded
+    /// to half their width, keeping the right edge.
+    #[test]
+    fn test_line_sizes() {
+        let mut canvas = Canvas::new(8, 3);
+        for y in 0..3 {
+            canvas.put_str(0, y, "|ab    |", CellStyle::default());
+        }
+        canvas.set_line_size(0, LineSize::DoubleWidth, 1);
+        canvas.set_line_size(1, LineSize::DoubleHeightTop, 1);
+        canvas.put_str(1, 2, "xy", CellStyle::default());
+        canvas.set_line_size(2, LineSize::DoubleHeightBottom, 1);
+
+        let expected = vec!["\u{1b}#6|ab|", "\u{1b}#3|ab|", "\u{1b}#4|ab|"];
+        assert_eq!(expected, canvas.to_ansi_rows_for(ColorSupport::TrueColor));
+        assert_eq!("|xy    |", canvas.to_plain_rows()[2]);
+    }
+
+    /// Verifies that line sizes are only copied with whole rows.
+    #[test]
+    fn test_blit_line_sizes() {
+        let mut row = Canvas::new(2, 1);
+        row.set_line_size(0, LineSize::DoubleWidth, 0);
+
+        let mut canvas = Canvas::new(2, 2);
+        canvas.blit(&row, 0, 1);
+        canvas.blit(&row, 1, 0);
+
+        assert_eq!(LineSize::Single, canvas.line_size(0));
+        assert_eq!(LineSize::DoubleWidth, canvas.line_size(1));
+    }
+}
@@ -0,0 +1,14 @@
+/// Describes which edges of a banner are shared with neighbouring banners.
+///
+/// Shared edges are drawn once, using junction glyphs where they meet the rest of the border.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Joins {
+    /// The left edge is drawn by the banner to the left, so it is omitted.
+    pub left: bool,
+    /// The right edge is shared with the banner to the right, so its corners become junctions.
+    pub right: bool,
+    /// The top edge is shared with the banner above, so its corners become junctions.
+    pub top: bool,
+    /// The bottom edge is drawn by the banner below, so it is omitted.
+    pub bottom: bool,
+}
@@ -0,0 +1,300 @@
+/// Describes the weight of a box-drawing line.
+///
+/// Weights are ordered from lightest to strongest, so the stronger of two overlapping lines
+/// can be chosen with `max`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LineWeight {
+    Light,
+    Heavy,
+    Double,
+}
+
+/// Describes which neighbouring cells a box-drawing glyph connects to, and with what weight.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Connections {
+    pub up: Option<LineWeight>,
+    pub down: Option<LineWeight>,
+    pub left: Option<LineWeight>,
+    pub right: Option<LineWeight>,
+}
+
+/// The box-drawing glyphs, with the connections of each written as up, down, left and right,
+/// where `l` is light, `h` is heavy, `d` is double and `.` is not connected.
+const GLYPHS: [(char, &str); 113] = [
+    // Light, heavy and double lines
+    ('─', "..ll"), ('━', "..hh"), ('│', "ll.."), ('┃', "hh.."), ('═', "..dd"), ('║', "dd.."),
+    ('╴', "..l."), ('╵', "l..."), ('╶', "...l"), ('╷', ".l.."),
+    ('╸', "..h."), ('╹', "h..."), ('╺', "...h"), ('╻', ".h.."),
+    ('╼', "..lh"), ('╽', "lh.."), ('╾', "..hl"), ('╿', "hl.."),
+    // Corners
+    ('┌', ".l.l"), ('┍', ".l.h"), ('┎', ".h.l"), ('┏', ".h.h"),
+    ('┐', ".ll."), ('┑', ".lh."), ('┒', ".hl."), ('┓', ".hh."),
+    ('└', "l..l"), ('┕', "l..h"), ('┖', "h..l"), ('┗', "h..h"),
+    ('┘', "l.l."), ('┙', "l.h."), ('┚', "h.l."), ('┛', "h.h."),
+    ('╒', ".l.d"), ('╓', ".d.l"), ('╔', ".d.d"),
+    ('╕', ".ld."), ('╖', ".dl."), ('╗', ".dd."),
+    ('╘', "l..d"), ('╙', "d..l"), ('╚', "d..d"),
+    ('╛', "l.d."), ('╜', "d.l."), ('╝', "d.d."),
+    // Junctions with a vertical line
+    ('├', "ll.l"), ('┝', "ll.h"), ('┞', "hl.l"), ('┟', "lh.l"),
+    ('┠', "hh.l"), ('┡', "hl.h"), ('┢', "lh.h"), ('┣', "hh.h"),
+    ('┤', "lll."), ('┥', "llh."), ('┦', "hll."), ('┧', "lhl."),
+    ('┨', "hhl."), ('┩', "hlh."), ('┪', "lhh."), ('┫', "hhh."),
+    ('╞', "ll.d"), ('╟', "dd.l"), ('╠', "dd.d"),
+    ('╡', "lld."), ('╢', "ddl."), ('╣', "ddd."),
+    // Junctions with a horizontal line
+    ('┬', ".lll"), ('┭', ".lhl"), ('┮', ".llh"), ('┯', ".lhh"),
+    ('┰', ".hll"), ('┱', ".hhl"), ('┲', ".hlh"), ('┳', ".hhh"),
+    ('┴', "l.ll"), ('┵', "l.hl"), ('┶', "l.lh"), ('┷', "l.hh"),
+    ('┸', "h.ll"), ('┹', "h.hl"), ('┺', "h.lh"), ('┻', "h.hh"),
+    ('╤', ".ldd"), ('╥', ".dll"), ('╦', ".ddd"),
+    ('╧', "l.dd"), ('╨', "d.ll"), ('╩', "d.dd"),
+    // Crossings
+    ('┼', "llll"), ('┽', "llhl"), ('┾', "lllh"), ('┿', "llhh"),
+    ('╀', "hlll"), ('╁', "lhll"), ('╂', "hhll"), ('╃', "hlhl"),
+    ('╄', "hllh"), ('╅', "lhhl"), ('╆', "lhlh"), ('╇', "hlhh"),
+    ('╈', "lhhh"), ('╉', "hhhl"), ('╊', "hhlh"), ('╋', "hhhh"),
+    ('╪', "lldd"), ('╫', "ddll"), ('╬', "dddd"),
+    // Rounded corners are read as light corners, but never chosen as junctions
+    ('╭', ".l.l"), ('╮', ".ll."), ('╯', "l.l."), ('╰', "l..l"),
+];
+
+/// The ASCII glyphs that can be merged, with their connections.
+const ASCII_GLYPHS: [(char, &str); 4] = [('-', "..ll"), ('=', "..dd"), ('|', "ll.."), ('+', "llll")];
+
+impl Connections {
+    /// Returns the connections of a box-drawing or ASCII border glyph, or None if the glyph is
+    /// not one.
+    ///
+    /// # Arguments
+    ///
+    /// * `glyph` - The glyph to inspect.
+    pub fn of(glyph: &str) -> Option<Connections> {
+        let mut chars = glyph.chars();
+        let (c, rest) = (chars.next()?, chars.next());
+        if rest.is_some() {
+            return None;
+        }
+
+        GLYPHS
+            .iter()
+            .chain(ASCII_GLYPHS.iter())
+            .find(|(glyph, _)| *glyph == c)
+            .map(|(_, spec)| Connections::parse(spec))
+    }
+
+    /// Returns the box-drawing glyph that best draws these connections.
+    ///
+    /// Not every combination of weights has a glyph (e.g. double lines only join other lines
+    /// along a whole axis), so when there is no exact match each axis is drawn with its
+    /// strongest weight, and failing that every line is drawn light. Returns None if there
+    /// are no connections.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The connections to draw.
+    pub fn glyph(self: &Self) -> Option<char> {
+        let vertical = self.up.max(self.down);
+        let horizontal = self.left.max(self.right);
+        let uniform = Connections {
+            up: self.up.and(vertical),
+            down: self.down.and(vertical),
+            left: self.left.and(horizontal),
+            right: self.right.and(horizontal),
+        };
+        let light = |weight: Option<LineWeight>| weight.map(|_| LineWeight::Light);
+        let all_light = Connections {
+            up: light(self.up),
+            down: light(self.down),
+            left: light(self.left),
+            right: light(self.right),
+        };
+
+        [*self, uniform, all_light].iter().find_map(|connections| connections.exact())
+    }
+
+    /// Returns the ASCII glyph that draws these connections (`-`, `=`, `|` or `+`), or None if
+    /// there are no connections.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The connections to draw.
+    pub fn ascii_glyph(self: &Self) -> Option<char> {
+        let vertical = self.up.or(self.down);
+        let horizontal = self.left.max(self.right);
+        match (vertical, horizontal) {
+            (None, None) => None,
+            (None, Some(LineWeight::Double)) => Some('='),
+            (None, Some(_)) => Some('-'),
+            (Some(_), None) => Some('|'),
+            (Some(_), Some(_)) => Some('+'),
+        }
+    }
+
+    /// Returns the connections of both sets combined, keeping the stronger weight where both
+    /// connect in the same direction.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The first set of connections.
+    /// * `other` - The second set of connections.
+    pub fn union(self: &Self, other: Connections) -> Connections {
+        Connections {
+            up: self.up.max(other.up),
+            down: self.down.max(other.down),
+            left: self.left.max(other.left),
+            right: self.right.max(other.right),
+        }
+    }
+
+    /// Returns the glyph with exactly these connections, if there is one.
+    fn exact(self: &Self) -> Option<char> {
+        GLYPHS
+            .iter()
+            .find(|(glyph, spec)| !is_rounded(*glyph) && Connections::parse(spec) == *self)
+            .map(|(glyph, _)| *glyph)
+    }
+
+    /// Parses a connection spec from the glyph tables.
+    fn parse(spec: &str) -> Connections {
+        let weights: Vec<Option<LineWeight>> = spec
+            .chars()
+            .map(|c| match c {
+                'l' => Some(LineWeight::Light),
+                'h' => Some(LineWeight::Heavy),
+                'd' => Some(LineWeight::Double),
+                _ => None,
+            })
+            .collect();
+
+        Connections {
+            up: weights[0],
+            down: weights[1],
+            left: weights[2],
+            right: weights[3],
+        }
+    }
+}
+
+/// Returns true for the rounded corner glyphs.
+fn is_rounded(glyph: char) -> bool {
+    ('╭'..='╰').contains(&glyph)
+}
+
+/// Returns true for the ASCII glyphs that can be merged.
+fn is_ascii(glyph: &str) -> bool {
+    ASCII_GLYPHS.iter().any(|(c, _)| glyph.starts_with(*c))
+}
+
+/// Merges two overlapping border glyphs into the junction that joins them (e.g. `─` and `│`
+/// become `┼`, and `═` and `│` become `╪`).
+///
+/// If either glyph is an ASCII border glyph the junction is drawn in ASCII. Returns None if
+/// either glyph is not a border glyph.
+///
+/// # Arguments
+///
+/// * `below` - The glyph already in the cell.
+/// * `above` - The glyph drawn on top.
+pub fn merge(below: &str, above: &str) -> Option<char> {
+    let connections = Connections::of(below)?.union(Connections::of(above)?);
+    if is_ascii(below) || is_ascii(above) {
+        connections.ascii_glyph()
+    } else {
+        connections.glyph()
+    }
+}
+
+/// Joins a border glyph to the lines it meets in its neighbouring cells, so that an edge
+/// shared by two boxes keeps the weight of each (e.g. `┬` meeting a heavy line on its right
+/// becomes `┮`).
+///
+/// Each line of the glyph that meets a line of a neighbouring border glyph takes that line's
+/// weight. Lines are never added or removed. Returns None if the glyph is not a border glyph.
+///
+/// # Arguments
+///
+/// * `glyph` - The glyph to join.
+/// * `neighbours` - The glyphs above, below, to the left and to the right of it.
+pub fn connect(glyph: &str, neighbours: [Option<&str>; 4]) -> Option<char> {
+    let own = Connections::of(glyph)?;
+    let [up, down, left, right] = neighbours.map(|neighbour| neighbour.and_then(Connections::of));
+    let meet = |line: Option<LineWeight>, other: Option<LineWeight>| line.and(other).or(line);
+    let connections = Connections {
+        up: meet(own.up, up.and_then(|up| up.down)),
+        down: meet(own.down, down.and_then(|down| down.up)),
+        left: meet(own.left, left.and_then(|left| left.right)),
+        right: meet(own.right, right.and_then(|right| right.left)),
+    };
+    if is_ascii(glyph) {
+        connections.ascii_glyph()
+    } else {
+        connections.glyph()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verifies that crossing and touching edges merge into the matching junction.
+    #[test]
+    fn test_merge() {
+        assert_eq!(Some('┼'), merge("─", "│"));
+        assert_eq!(Some('┬'), merge("─", "┌").and_then(|c| merge(&c.to_string(), "┐")));
+        assert_eq!(Some('├'), merge("│", "┌"));
+        assert_eq!(Some('─'), merge("─", "─"));
+    }
+
+    /// Verifies that junctions keep the weight of each line that meets in them.
+    #[test]
+    fn test_merge_weights() {
+        assert_eq!(Some('╋'), merge("━", "┃"));
+        assert_eq!(Some('╬'), merge("═", "║"));
+        assert_eq!(Some('╪'), merge("═", "│"));
+        assert_eq!(Some('┿'), merge("━", "│"));
+        assert_eq!(Some('╂'), merge("─", "┃"));
+        assert_eq!(Some('┢'), merge("│", "┏"));
+    }
+
+    /// Verifies that glyphs take the weight of the lines they meet in neighbouring cells.
+    #[test]
+    fn test_connect() {
+        assert_eq!(Some('┮'), connect("┬", [None, Some("│"), Some("─"), Some("━")]));
+        assert_eq!(Some('┢'), connect("┣", [Some("│"), Some("┃"), None, Some("━")]));
+        assert_eq!(Some('│'), connect("│", [None, None, Some("━"), Some("━")]));
+        assert_eq!(Some('+'), connect("+", [Some("┃"), None, None, None]));
+        assert_eq!(None, connect("a", [None, None, None, None]));
+    }
+
+    /// Verifies that combinations without a glyph fall back to the closest available glyph.
+    #[test]
+    fn test_glyph_fallback() {
+        let mixed = Connections::of("║").unwrap().union(Connections::of("╶").unwrap());
+        assert_eq!(Some('╟'), mixed.glyph());
+        let clash = Connections::of("═").unwrap().union(Connections::of("┃").unwrap());
+        assert_eq!(Some('┼'), clash.glyph());
+        assert_eq!(None, Connections::default().glyph());
+    }
+
+    /// Verifies that ASCII borders merge into ASCII junctions.
+    #[test]
+    fn test_merge_ascii() {
+        assert_eq!(Some('+'), merge("-", "|"));
+        assert_eq!(Some('+'), merge("│", "-"));
+        assert_eq!(Some('='), merge("=", "-"));
+    }
+
+    /// Verifies that glyphs that are not border glyphs are not merged.
+    #[test]
+    fn test_merge_non_border() {
+        assert_eq!(None, merge("a", "│"));
+        assert_eq!(None, merge("│", " "));
+    }
+
+    /// Verifies that rounded corners are read as light corners.
+    #[test]
+    fn test_merge_rounded() {
+        assert_eq!(Some('├'), merge("╭", "╰"));
+    }
+}
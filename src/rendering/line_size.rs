@@ -0,0 +1,28 @@
+/// Describes how the terminal draws a row, using the DEC line size sequences.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LineSize {
+    /// The row is drawn normally.
+    Single,
+    /// Every character in the row is drawn twice as wide (`ESC # 6`).
+    DoubleWidth,
+    /// The row shows the top half of double height, double width characters (`ESC # 3`).
+    DoubleHeightTop,
+    /// The row shows the bottom half of double height, double width characters (`ESC # 4`).
+    DoubleHeightBottom,
+}
+
+impl LineSize {
+    /// Returns the escape sequence that sets this size for the row the cursor is on.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The line size.
+    pub fn escape(self: &Self) -> &'static str {
+        match self {
+            LineSize::Single => "\u{1b}#5",
+            LineSize::DoubleWidth => "\u{1b}#6",
+            LineSize::DoubleHeightTop => "\u{1b}#3",
+            LineSize::DoubleHeightBottom => "\u{1b}#4",
+        }
+    }
+}
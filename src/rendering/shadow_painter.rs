@@ -0,0 +1,87 @@
+use super::border_painter::repeat_to_width;
+use super::{Canvas, CellStyle};
+use crate::style::ShadowStyle;
+
+/// Represents a shadow painter.
+///
+/// This is used to cast a drop shadow behind a rendered banner.
+pub struct ShadowPainter<'a> {
+    style: &'a ShadowStyle,
+    no_color_codes: bool,
+}
+
+impl ShadowPainter<'_> {
+    /// Creates a new ShadowPainter.
+    ///
+    /// # Arguments
+    ///
+    /// * `style` - A description of the shadow style.
+    /// * `no_color_codes` - A flag indicating whether to suppress color codes in output.
+    pub fn new(style: &ShadowStyle, no_color_codes: bool) -> ShadowPainter<'_> {
+        ShadowPainter { style, no_color_codes }
+    }
+
+    /// Returns a canvas holding the box with its shadow behind it.
+    ///
+    /// The canvas is larger than the box by the shadow offset. The cells above and to the left
+    /// of the shadow, next to the box, are left blank.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The shadow painter.
+    /// * `canvas` - The rendered box to cast a shadow from.
+    pub fn paint(self: &Self, canvas: &Canvas) -> Canvas {
+        let (offset_x, offset_y) = (self.style.offset_x as usize, self.style.offset_y as usize);
+        let (width, height) = (canvas.width(), canvas.height());
+        let mut result = Canvas::new(width + offset_x, height + offset_y);
+        let style = if self.no_color_codes { CellStyle::default() } else { CellStyle::fg(self.style.color) };
+
+        if width > 0 && height > 0 {
+            for y in offset_y..height + offset_y {
+                // Only the part of the shadow that sticks out from under the box is drawn
+                let start = if y < height { width } else { offset_x };
+                let shadow = repeat_to_width(&self.style.glyph, width + offset_x - start);
+                result.put_str(start, y, &shadow, style);
+            }
+        }
+        result.blit(canvas, 0, 0);
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::Color;
+
+    /// Verifies that the shadow is drawn below and to the right of the box.
+    #[test]
+    fn test_paint() {
+        let mut canvas = Canvas::new(3, 2);
+        canvas.put_str(0, 0, "abc", CellStyle::default());
+        canvas.put_str(0, 1, "def", CellStyle::default());
+
+        let style = ShadowStyle::new();
+        let result = ShadowPainter::new(&style, false).paint(&canvas);
+
+        assert_eq!(vec!["abc ", "def░", " ░░░"], result.to_plain_rows());
+        assert_eq!(CellStyle::fg(Color::Fixed(8)), result.cell(3, 1).unwrap().style);
+        assert_eq!(CellStyle::default(), result.cell(0, 2).unwrap().style);
+    }
+
+    /// Verifies larger offsets, patterns and monochrome output.
+    #[test]
+    fn test_paint_offset_pattern() {
+        let mut canvas = Canvas::new(2, 1);
+        canvas.put_str(0, 0, "ab", CellStyle::default());
+
+        let mut style = ShadowStyle::new();
+        style.glyph = String::from("▒░");
+        style.offset_x = 2;
+        let result = ShadowPainter::new(&style, true).paint(&canvas);
+
+        assert_eq!(vec!["ab  ", "  ▒░"], result.to_plain_rows());
+        assert_eq!(CellStyle::default(), result.cell(3, 1).unwrap().style);
+    }
+}
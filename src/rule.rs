@@ -0,0 +1,212 @@
+use crate::content::{Line, TextLine};
+use crate::layout::{HorizontalAlignment, WidthMode};
+use crate::rendering::{BorderPainter, Canvas, CellStyle};
+use crate::style::{BorderPart, HeaderLevel, Style};
+use crate::terminal;
+
+/// A standalone section header drawn as a horizontal rule with a title in it, without a box
+/// (e.g. `── Building crate ──────` or `=== TESTS ===`).
+///
+/// The rule is drawn with the top border pattern of the style's border glyphs, in the border's
+/// colors, and the title with the element style of the rule's header level.
+pub struct Rule<'a> {
+    pub width: u8,
+    pub auto_widen: bool,
+    /// Describes how the width of the rule is chosen.
+    pub width_mode: WidthMode,
+    /// Overrides the detected terminal width used by the width modes.
+    pub terminal_width: Option<u16>,
+    /// Describes where the title sits along the rule.
+    pub alignment: HorizontalAlignment,
+    /// The number of rule columns before a left aligned title, or after a right aligned title.
+    pub indent: u8,
+    /// The header level whose element style the title is drawn with.
+    pub level: HeaderLevel,
+    style: &'a Style,
+    title: Option<String>,
+}
+
+impl<'a> Rule<'a> {
+    /// Creates a new rule with default values.
+    pub fn new(style: &'a Style) -> Rule<'a> {
+        Rule {
+            width: 50,
+            auto_widen: true,
+            width_mode: WidthMode::Auto,
+            terminal_width: None,
+            alignment: HorizontalAlignment::Left,
+            indent: 2,
+            level: HeaderLevel::H1,
+            style,
+            title: None,
+        }
+    }
+
+    /// Sets the title shown within the rule.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The rule to set the title of.
+    /// * `title` - The title text.
+    pub fn set_title(&mut self, title: &str) {
+        self.title = Some(self.style.prepare(title));
+
+        // Check if rule needs to be widened
+        let title_width = self.title_width();
+        if self.auto_widen && title_width > self.width {
+            self.width = title_width
+        }
+    }
+
+    /// Assembles the rule.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The rule to assemble.
+    pub fn assemble(self: &Rule<'a>) -> String {
+        self.rows().iter().map(|row| format!("{}\r\n", row)).collect()
+    }
+
+    /// Renders the rule as a list of rows, without line endings.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The rule to render.
+    pub fn rows(self: &Rule<'a>) -> Vec<String> {
+        self.canvas().to_ansi_rows()
+    }
+
+    /// Renders the rule into a canvas of styled cells.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The rule to render.
+    pub fn canvas(self: &Rule<'a>) -> Canvas {
+        let width = self.content_width();
+        let mut canvas = Canvas::new(width as usize, 1);
+        let border_painter = BorderPainter::new(&self.style.border, self.style.no_color_codes, width);
+        border_painter.paint_edge(&mut canvas, 0, 0, BorderPart::Top, None, &self.style.border.glyphs.top, None, None);
+
+        // Clear a gap in the rule for the title, with a space on each side
+        if let Some(title) = &self.title {
+            let line = TextLine::new(title, self.style.header_style(&self.level));
+            let (width, gap, indent) = (width as usize, line.width() as usize + 2, self.indent as usize);
+            let start = match self.alignment {
+                HorizontalAlignment::Left => indent,
+                HorizontalAlignment::Center => width.saturating_sub(gap) / 2,
+                HorizontalAlignment::Right => width.saturating_sub(gap + indent),
+            };
+            let start = start.min(width.saturating_sub(gap));
+            canvas.put_str(start, 0, &" ".repeat(gap), CellStyle::default());
+            line.draw(&mut canvas, start + 1, 0, width as u8);
+        }
+
+        if self.style.no_color_codes {
+            canvas.clear_styles();
+        }
+        canvas
+    }
+
+    /// Returns the width of the rule, as chosen by the width mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The rule to measure.
+    pub fn content_width(self: &Rule<'a>) -> u8 {
+        let terminal = || {
+            let columns = self.terminal_width.or_else(terminal::detected_width);
+            columns.map(|columns| columns.min(u8::MAX as u16) as u8)
+        };
+
+        self.width_mode.resolve(self.width, || self.title_width(), terminal, 0)
+    }
+
+    /// Returns the width needed to show the title, with its indent and a column of the rule on
+    /// each side.
+    fn title_width(self: &Rule<'a>) -> u8 {
+        self.title.as_ref().map_or(0, |title| {
+            let line = TextLine::new(title, self.style.header_style(&self.level));
+            let indent = if self.alignment == HorizontalAlignment::Center { 1 } else { self.indent as usize };
+            (line.width() as usize + 2 + indent + 1).min(u8::MAX as usize) as u8
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::{Color, TextTransform};
+    use crate::text::ansi;
+
+    /// Verifies a left aligned title with the default rule glyph.
+    #[test]
+    fn test_assemble_left() {
+        let mut style: Style = Style::new();
+        style.no_color_codes = true;
+
+        let mut rule: Rule = Rule::new(&style);
+        rule.width = 24;
+        rule.set_title("Building crate");
+
+        assert_eq!("── Building crate ──────\r\n", rule.assemble());
+    }
+
+    /// Verifies centered and right aligned titles with a custom rule pattern and transform.
+    #[test]
+    fn test_assemble_alignment() {
+        let mut style: Style = Style::new();
+        style.no_color_codes = true;
+        style.border.glyphs.top = String::from("=");
+        style.h2.transform = TextTransform::Uppercase;
+
+        let mut rule: Rule = Rule::new(&style);
+        rule.width = 13;
+        rule.level = HeaderLevel::H2;
+        rule.alignment = HorizontalAlignment::Center;
+        rule.set_title("tests");
+        assert_eq!("=== TESTS ===\r\n", rule.assemble());
+
+        rule.alignment = HorizontalAlignment::Right;
+        rule.indent = 1;
+        assert_eq!("===== TESTS =\r\n", rule.assemble());
+    }
+
+    /// Verifies that the rule widens to fit its title and follows the width modes.
+    #[test]
+    fn test_content_width() {
+        let mut style: Style = Style::new();
+        style.no_color_codes = true;
+
+        let mut rule: Rule = Rule::new(&style);
+        rule.width = 4;
+        rule.set_title("Deploy");
+        assert_eq!(11, rule.content_width());
+        assert_eq!("── Deploy ─\r\n", rule.assemble());
+
+        rule.width_mode = WidthMode::FillTerminal;
+        rule.terminal_width = Some(20);
+        assert_eq!(20, ansi::visible_width(&rule.rows()[0]));
+
+        rule.title = None;
+        rule.width_mode = WidthMode::Fixed(3);
+        assert_eq!("───\r\n", rule.assemble());
+    }
+
+    /// Verifies that the rule is drawn in the border color and the title in the header color.
+    #[test]
+    fn test_canvas_colors() {
+        let mut style: Style = Style::new();
+        style.border.color = Color::Blue;
+        style.h1.content_color = Color::Red;
+
+        let mut rule: Rule = Rule::new(&style);
+        rule.width = 8;
+        rule.set_title("ab");
+
+        let canvas = rule.canvas();
+        assert_eq!(vec!["── ab ──"], canvas.to_plain_rows());
+        assert_eq!(CellStyle::fg(Color::Blue), canvas.cell(0, 0).unwrap().style);
+        assert_eq!(CellStyle::default(), canvas.cell(2, 0).unwrap().style);
+        assert_eq!(CellStyle::fg(Color::Red), canvas.cell(3, 0).unwrap().style);
+    }
+}
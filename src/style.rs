@@ -1,12 +1,41 @@
+use std::collections::HashMap;
+use crate::text::{ansi, sanitize};
+
+mod ansi_input;
 mod color;
+mod color_support;
+mod border_colors;
 mod border_glyphs;
+mod border_part;
 mod border_style;
 mod element_style;
+mod gradient;
+mod gradient_direction;
+mod line_scale;
+mod numbering;
+mod sanitize_policy;
+mod shadow_style;
+mod text_effect;
+mod text_transform;
 
+pub use ansi_input::AnsiInput;
 pub use color::Color;
+pub use color_support::ColorSupport;
+pub use border_colors::BorderColors;
 pub use border_glyphs::BorderGlyphs;
+pub use border_part::BorderPart;
 pub use border_style::BorderStyle;
 pub use element_style::ElementStyle;
+pub use gradient::Gradient;
+pub use gradient_direction::GradientDirection;
+pub use line_scale::LineScale;
+pub use numbering::Numbering;
+pub use sanitize_policy::SanitizePolicy;
+pub use shadow_style::ShadowStyle;
+pub use text_effect::TextEffect;
+pub use text_transform::TextTransform;
+
+const DEFAULT_TAB_WIDTH: u8 = 4;
 
 /**
  * Defines a banner style.
@@ -18,11 +47,31 @@ pub struct Style {
      */
     pub no_color_codes: bool,
 
+    /**
+     * Specifies how escape sequences already present in content are handled.
+     */
+    pub ansi_input: AnsiInput,
+
+    /**
+     * Specifies how control characters and escape sequences in content are neutralized.
+     */
+    pub sanitize: SanitizePolicy,
+
+    /**
+     * The distance between tab stops used when expanding tabs in content.
+     */
+    pub tab_width: u8,
+
     /**
      * The border color.
      */
     pub border: BorderStyle,
 
+    /**
+     * Describes a drop shadow cast by the banner, or None for no shadow.
+     */
+    pub shadow: Option<ShadowStyle>,
+
     /**
      * Defines the style for H1 elements.
      */
@@ -38,16 +87,64 @@ pub struct Style {
      */
     pub h3: ElementStyle,
 
+    /**
+     * Defines the style for H4 elements.
+     */
+    pub h4: ElementStyle,
+
+    /**
+     * Defines the style for H5 elements.
+     */
+    pub h5: ElementStyle,
+
+    /**
+     * Defines the style for H6 elements.
+     */
+    pub h6: ElementStyle,
+
     /**
      * Defines the style for text elements.
      */
-    pub text: ElementStyle
+    pub text: ElementStyle,
+
+    /**
+     * Named style classes (e.g. "warning", "muted") that can be applied to lines.
+     */
+    classes: HashMap<String, ElementStyle>
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum HeaderLevel {
     H1,
     H2,
-    H3
+    H3,
+    H4,
+    H5,
+    H6
+}
+
+impl HeaderLevel {
+    /// Every header level, from the highest to the lowest.
+    pub const ALL: [HeaderLevel; 6] = [
+        HeaderLevel::H1,
+        HeaderLevel::H2,
+        HeaderLevel::H3,
+        HeaderLevel::H4,
+        HeaderLevel::H5,
+        HeaderLevel::H6
+    ];
+
+    /// Returns the position of the level, counting from 0 for H1.
+    pub fn index(self: &HeaderLevel) -> usize {
+        match self {
+            HeaderLevel::H1 => 0,
+            HeaderLevel::H2 => 1,
+            HeaderLevel::H3 => 2,
+            HeaderLevel::H4 => 3,
+            HeaderLevel::H5 => 4,
+            HeaderLevel::H6 => 5
+        }
+    }
 }
 
 impl Style {
@@ -55,11 +152,19 @@ impl Style {
     pub fn new() -> Style {
         Style {
             no_color_codes: false,
+            ansi_input: AnsiInput::Preserve,
+            sanitize: SanitizePolicy::Escape,
+            tab_width: DEFAULT_TAB_WIDTH,
             border: BorderStyle::new(),
+            shadow: None,
             h1: ElementStyle::new(),
             h2: ElementStyle::new(),
             h3: ElementStyle::new(),
-            text: ElementStyle::new()
+            h4: ElementStyle::new(),
+            h5: ElementStyle::new(),
+            h6: ElementStyle::new(),
+            text: ElementStyle::new(),
+            classes: HashMap::new()
         }
     }
 
@@ -76,7 +181,83 @@ impl Style {
         match level {
             HeaderLevel::H1 => &self.h1,
             HeaderLevel::H2 => &self.h2,
-            HeaderLevel::H3 => &self.h3
+            HeaderLevel::H3 => &self.h3,
+            HeaderLevel::H4 => &self.h4,
+            HeaderLevel::H5 => &self.h5,
+            HeaderLevel::H6 => &self.h6
         }
     }
+
+    /// Registers a named style class, replacing any existing class with the same name.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The style to register the class with.
+    /// * `name` - The class name (e.g. "warning").
+    /// * `style` - The element style applied to lines using this class.
+    pub fn add_class(self: &mut Style, name: &str, style: ElementStyle) {
+        self.classes.insert(name.to_string(), style);
+    }
+
+    /// Applies the style's input handling rules to content before it is rendered.
+    ///
+    /// Incoming escape sequences are preserved or stripped, then the sanitize policy is applied
+    /// so that control characters cannot corrupt the output or the terminal.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The style describing the input handling rules.
+    /// * `text` - The content to prepare.
+    pub(crate) fn prepare(self: &Style, text: &str) -> String {
+        let text = match self.ansi_input {
+            AnsiInput::Preserve => text.to_string(),
+            AnsiInput::Strip => ansi::strip(text),
+        };
+        sanitize::sanitize(&text, self.sanitize, self.ansi_input == AnsiInput::Preserve)
+    }
+
+    /// Returns the element style registered for a class, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The style to look the class up in.
+    /// * `name` - The class name.
+    pub fn class_style(self: &Style, name: &str) -> Option<&ElementStyle> {
+        self.classes.get(name)
+    }
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Style::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verifies that each header level maps to its own element style.
+    #[test]
+    fn test_header_style_levels() {
+        let mut style = Style::new();
+        style.h4.content_color = Color::Red;
+        style.h6.content_color = Color::Blue;
+
+        assert_eq!(Color::Red, style.header_style(&HeaderLevel::H4).content_color);
+        assert_eq!(Color::White, style.header_style(&HeaderLevel::H5).content_color);
+        assert_eq!(Color::Blue, style.header_style(&HeaderLevel::H6).content_color);
+    }
+
+    /// Verifies that registered classes can be looked up by name.
+    #[test]
+    fn test_class_style_lookup() {
+        let mut style = Style::new();
+        let mut warning = ElementStyle::new();
+        warning.content_color = Color::Yellow;
+        style.add_class("warning", warning);
+
+        assert_eq!(Color::Yellow, style.class_style("warning").unwrap().content_color);
+        assert!(style.class_style("muted").is_none());
+    }
 }
\ No newline at end of file
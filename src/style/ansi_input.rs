@@ -0,0 +1,8 @@
+/// Describes how escape sequences already present in content are handled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AnsiInput {
+    /// Keeps incoming color codes, resetting them before the border is drawn.
+    Preserve,
+    /// Removes incoming escape sequences, leaving only the visible text.
+    Strip,
+}
@@ -0,0 +1,43 @@
+use super::{BorderPart, Color};
+
+/// Describes colors for individual sides and corners of a border.
+///
+/// Parts without a color of their own use the border's gradient or color. Corners without a
+/// color of their own use the color of the top or bottom side they sit on.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BorderColors {
+    pub top: Option<Color>,
+    pub bottom: Option<Color>,
+    pub left: Option<Color>,
+    pub right: Option<Color>,
+    pub top_left: Option<Color>,
+    pub top_right: Option<Color>,
+    pub bottom_left: Option<Color>,
+    pub bottom_right: Option<Color>,
+}
+
+impl BorderColors {
+    /// Creates a new set of border colors, with no part colored.
+    pub fn new() -> BorderColors {
+        BorderColors::default()
+    }
+
+    /// Returns the color of a part of the border, if one is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The border colors.
+    /// * `part` - The part of the border.
+    pub fn get(self: &Self, part: BorderPart) -> Option<Color> {
+        match part {
+            BorderPart::Top => self.top,
+            BorderPart::Bottom => self.bottom,
+            BorderPart::Left => self.left,
+            BorderPart::Right => self.right,
+            BorderPart::TopLeft => self.top_left.or(self.top),
+            BorderPart::TopRight => self.top_right.or(self.top),
+            BorderPart::BottomLeft => self.bottom_left.or(self.bottom),
+            BorderPart::BottomRight => self.bottom_right.or(self.bottom),
+        }
+    }
+}
@@ -1,24 +1,46 @@
-const DEFAULT_TOP_LEFT_CHAR: char = '┌';
-const DEFAULT_TOP_RIGHT_CHAR: char = '┐';
-const DEFAULT_BOTTOM_LEFT_CHAR: char = '└';
-const DEFAULT_BOTTOM_RIGHT_CHAR: char = '┘';
-const DEFAULT_TOP_CHAR: char = '─';
-const DEFAULT_LEFT_CHAR: char = '│';
-const DEFAULT_RIGHT_CHAR: char = '│';
-const DEFAULT_BOTTOM_CHAR: char = '─';
+use crate::text::ansi;
+
+const DEFAULT_TOP_LEFT_GLYPH: &str = "┌";
+const DEFAULT_TOP_RIGHT_GLYPH: &str = "┐";
+const DEFAULT_BOTTOM_LEFT_GLYPH: &str = "└";
+const DEFAULT_BOTTOM_RIGHT_GLYPH: &str = "┘";
+const DEFAULT_TOP_GLYPH: &str = "─";
+const DEFAULT_LEFT_GLYPH: &str = "│";
+const DEFAULT_RIGHT_GLYPH: &str = "│";
+const DEFAULT_BOTTOM_GLYPH: &str = "─";
+const DEFAULT_TOP_JUNCTION_GLYPH: &str = "┬";
+const DEFAULT_BOTTOM_JUNCTION_GLYPH: &str = "┴";
+const DEFAULT_LEFT_JUNCTION_GLYPH: &str = "├";
+const DEFAULT_RIGHT_JUNCTION_GLYPH: &str = "┤";
+const DEFAULT_CROSS_GLYPH: &str = "┼";
 
 /**
  * Describes the glyphs used to render a border.
+ *
+ * Each glyph is a string. Corners and sides may be several characters wide, and the top and
+ * bottom edges repeat their string as a pattern (e.g. `-=` or `~*~`), trimmed to fit.
  */
 pub struct BorderGlyphs {
-    pub top_left: char,
-    pub top_right: char,
-    pub bottom_left: char,
-    pub bottom_right: char,
-    pub top: char,
-    pub left: char,
-    pub right: char,
-    pub bottom: char,
+    pub top_left: String,
+    pub top_right: String,
+    pub bottom_left: String,
+    pub bottom_right: String,
+    /// The pattern repeated along the top edge.
+    pub top: String,
+    pub left: String,
+    pub right: String,
+    /// The pattern repeated along the bottom edge.
+    pub bottom: String,
+    /// Joins the top edge to a vertical edge below it (e.g. between side-by-side banners).
+    pub top_junction: String,
+    /// Joins the bottom edge to a vertical edge above it.
+    pub bottom_junction: String,
+    /// Joins the left edge to a horizontal edge to its right (e.g. between stacked banners).
+    pub left_junction: String,
+    /// Joins the right edge to a horizontal edge to its left.
+    pub right_junction: String,
+    /// Joins horizontal and vertical edges that cross.
+    pub cross: String,
 }
 
 
@@ -27,15 +49,84 @@ impl BorderGlyphs {
      * Creates a new border glyphs descriptor with default values.
      */
     pub fn new() -> BorderGlyphs {
-        return BorderGlyphs {
-            top_left: DEFAULT_TOP_LEFT_CHAR,
-            top_right: DEFAULT_TOP_RIGHT_CHAR,
-            bottom_left: DEFAULT_BOTTOM_LEFT_CHAR,
-            bottom_right: DEFAULT_BOTTOM_RIGHT_CHAR,
-            top: DEFAULT_TOP_CHAR,
-            left: DEFAULT_LEFT_CHAR,
-            right: DEFAULT_RIGHT_CHAR,
-            bottom: DEFAULT_BOTTOM_CHAR,
-        };
+        BorderGlyphs {
+            top_left: String::from(DEFAULT_TOP_LEFT_GLYPH),
+            top_right: String::from(DEFAULT_TOP_RIGHT_GLYPH),
+            bottom_left: String::from(DEFAULT_BOTTOM_LEFT_GLYPH),
+            bottom_right: String::from(DEFAULT_BOTTOM_RIGHT_GLYPH),
+            top: String::from(DEFAULT_TOP_GLYPH),
+            left: String::from(DEFAULT_LEFT_GLYPH),
+            right: String::from(DEFAULT_RIGHT_GLYPH),
+            bottom: String::from(DEFAULT_BOTTOM_GLYPH),
+            top_junction: String::from(DEFAULT_TOP_JUNCTION_GLYPH),
+            bottom_junction: String::from(DEFAULT_BOTTOM_JUNCTION_GLYPH),
+            left_junction: String::from(DEFAULT_LEFT_JUNCTION_GLYPH),
+            right_junction: String::from(DEFAULT_RIGHT_JUNCTION_GLYPH),
+            cross: String::from(DEFAULT_CROSS_GLYPH),
+        }
+    }
+
+    /**
+     * Creates a border glyphs descriptor that draws heavy lines (e.g. `┏━┓`).
+     */
+    pub fn heavy() -> BorderGlyphs {
+        BorderGlyphs::from_chars("┏┓┗┛━┃┃━┳┻┣┫╋")
+    }
+
+    /**
+     * Creates a border glyphs descriptor that draws double lines (e.g. `╔═╗`).
+     */
+    pub fn double() -> BorderGlyphs {
+        BorderGlyphs::from_chars("╔╗╚╝═║║═╦╩╠╣╬")
+    }
+
+    /**
+     * Creates a border glyphs descriptor that draws with ASCII characters (e.g. `+-+`).
+     */
+    pub fn ascii() -> BorderGlyphs {
+        BorderGlyphs::from_chars("++++-||-+++++")
+    }
+
+    /**
+     * Returns the number of columns taken by the left border, the widest of its glyphs.
+     */
+    pub fn left_width(self: &Self) -> usize {
+        [&self.top_left, &self.left, &self.bottom_left].iter().map(|glyph| ansi::visible_width(glyph)).max().unwrap_or(0)
+    }
+
+    /**
+     * Returns the number of columns taken by the right border, the widest of its glyphs.
+     */
+    pub fn right_width(self: &Self) -> usize {
+        [&self.top_right, &self.right, &self.bottom_right].iter().map(|glyph| ansi::visible_width(glyph)).max().unwrap_or(0)
+    }
+
+    /**
+     * Creates a border glyphs descriptor from a string listing one character per glyph, in
+     * field order.
+     */
+    fn from_chars(chars: &str) -> BorderGlyphs {
+        let glyphs: Vec<String> = chars.chars().map(String::from).collect();
+        BorderGlyphs {
+            top_left: glyphs[0].clone(),
+            top_right: glyphs[1].clone(),
+            bottom_left: glyphs[2].clone(),
+            bottom_right: glyphs[3].clone(),
+            top: glyphs[4].clone(),
+            left: glyphs[5].clone(),
+            right: glyphs[6].clone(),
+            bottom: glyphs[7].clone(),
+            top_junction: glyphs[8].clone(),
+            bottom_junction: glyphs[9].clone(),
+            left_junction: glyphs[10].clone(),
+            right_junction: glyphs[11].clone(),
+            cross: glyphs[12].clone(),
+        }
+    }
+}
+
+impl Default for BorderGlyphs {
+    fn default() -> Self {
+        BorderGlyphs::new()
     }
 }
@@ -0,0 +1,12 @@
+/// Describes a part of a border.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BorderPart {
+    TopLeft,
+    Top,
+    TopRight,
+    Left,
+    Right,
+    BottomLeft,
+    Bottom,
+    BottomRight,
+}
@@ -1,4 +1,4 @@
-use super::{BorderGlyphs, Color};
+use super::{BorderColors, BorderGlyphs, BorderPart, Color, Gradient};
 
 /**
  * Describes a border style.
@@ -14,6 +14,17 @@ pub struct BorderStyle {
      */
     pub color: Color,
 
+    /**
+     * Specifies colors for individual sides and corners, which take precedence over the
+     * gradient and the border color.
+     */
+    pub colors: BorderColors,
+
+    /**
+     * Specifies a gradient across the whole border, which takes precedence over the border color.
+     */
+    pub gradient: Option<Gradient>,
+
     /**
      * Specifies whether the border is visible.
      * True to show the border or false to hide the border.
@@ -26,7 +37,33 @@ impl BorderStyle {
         BorderStyle {
             glyphs: BorderGlyphs::new(),
             color: Color::White,
+            colors: BorderColors::new(),
+            gradient: None,
             is_visible: true
         }
     }
-}
\ No newline at end of file
+
+    /**
+     * Returns the color of a border cell.
+     *
+     * # Arguments
+     *
+     * * `part` - The part of the border the cell belongs to.
+     * * `x` - The column of the cell within the bordered area.
+     * * `y` - The row of the cell within the bordered area.
+     * * `width` - The number of columns in the bordered area.
+     * * `height` - The number of rows in the bordered area.
+     */
+    pub fn color_at(&self, part: BorderPart, x: usize, y: usize, width: usize, height: usize) -> Color {
+        self.colors
+            .get(part)
+            .or_else(|| self.gradient.as_ref().and_then(|gradient| gradient.color_in(x, y, width, height)))
+            .unwrap_or(self.color)
+    }
+}
+
+impl Default for BorderStyle {
+    fn default() -> Self {
+        BorderStyle::new()
+    }
+}
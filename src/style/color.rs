@@ -1,3 +1,16 @@
+use super::ColorSupport;
+use std::fmt;
+
+/// The RGB values of the 16 standard and bright colors, as used by xterm.
+const ANSI_16_RGB: [(u8, u8, u8); 16] = [
+    (0, 0, 0), (205, 0, 0), (0, 205, 0), (205, 205, 0), (0, 0, 238), (205, 0, 205), (0, 205, 205), (229, 229, 229),
+    (127, 127, 127), (255, 0, 0), (0, 255, 0), (255, 255, 0), (92, 92, 255), (255, 0, 255), (0, 255, 255), (255, 255, 255),
+];
+
+/// The channel levels of the 6x6x6 color cube in the 256 color palette.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Color {
     Red,
     Blue,
@@ -7,22 +20,205 @@ pub enum Color {
     Cyan,
     White,
     Black,
+    /// A color from the 256 color palette. Indexes 0-15 are the standard and bright colors.
+    Fixed(u8),
+    /// A truecolor (24-bit) color.
+    Rgb(u8, u8, u8),
 }
 
 impl Color {
     /**
-     * Converts a Color to a string.
+     * Looks up a Color by its lowercase name (e.g. "red").
+     */
+    pub fn from_name(name: &str) -> Option<Color> {
+        match name {
+            "red" => Some(Color::Red),
+            "blue" => Some(Color::Blue),
+            "green" => Some(Color::Green),
+            "yellow" => Some(Color::Yellow),
+            "magenta" => Some(Color::Magenta),
+            "cyan" => Some(Color::Cyan),
+            "white" => Some(Color::White),
+            "black" => Some(Color::Black),
+            _ => None,
+        }
+    }
+
+    /**
+     * Returns the named color for a standard ANSI color index (0-7).
+     */
+    pub fn from_ansi_index(index: u8) -> Color {
+        match index {
+            0 => Color::Black,
+            1 => Color::Red,
+            2 => Color::Green,
+            3 => Color::Yellow,
+            4 => Color::Blue,
+            5 => Color::Magenta,
+            6 => Color::Cyan,
+            7 => Color::White,
+            _ => Color::Fixed(index),
+        }
+    }
+
+    /**
+     * Returns the RGB values of this color. Named and palette colors use the xterm palette.
+     */
+    pub fn to_rgb(&self) -> (u8, u8, u8) {
+        match *self {
+            Color::Rgb(r, g, b) => (r, g, b),
+            Color::Fixed(index) if index < 16 => ANSI_16_RGB[index as usize],
+            Color::Fixed(index) if index < 232 => {
+                let cube = index - 16;
+                let level = |n: u8| CUBE_LEVELS[n as usize];
+                (level(cube / 36), level(cube / 6 % 6), level(cube % 6))
+            }
+            Color::Fixed(index) => {
+                let gray = 8 + (index - 232) * 10;
+                (gray, gray, gray)
+            }
+            named => ANSI_16_RGB[named.ansi_index() as usize],
+        }
+    }
+
+    /**
+     * Returns the closest color the terminal can display, leaving colors it supports as they are.
+     */
+    pub fn degrade(&self, support: ColorSupport) -> Color {
+        match *self {
+            Color::Rgb(..) if support < ColorSupport::TrueColor => {
+                if support == ColorSupport::Ansi256 {
+                    self.nearest_256()
+                } else {
+                    self.nearest_16()
+                }
+            }
+            Color::Fixed(index) if index >= 16 && support < ColorSupport::Ansi256 => self.nearest_16(),
+            color => color,
+        }
+    }
+
+    /**
+     * Returns a color partway between this color and another, where 0 is this color and 1 is
+     * the other.
+     */
+    pub fn mix(&self, other: Color, amount: f32) -> Color {
+        let (from, to) = (self.to_rgb(), other.to_rgb());
+        let amount = amount.clamp(0.0, 1.0);
+        let channel = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * amount).round() as u8;
+        Color::Rgb(channel(from.0, to.0), channel(from.1, to.1), channel(from.2, to.2))
+    }
+
+    /**
+     * Returns the fully saturated color for a hue, in degrees around the color wheel where 0 is
+     * red, 120 is green and 240 is blue.
+     */
+    pub fn from_hue(hue: f32) -> Color {
+        let hue = hue.rem_euclid(360.0) / 60.0;
+        let rising = ((1.0 - (hue % 2.0 - 1.0).abs()) * 255.0).round() as u8;
+        match hue as u8 {
+            0 => Color::Rgb(255, rising, 0),
+            1 => Color::Rgb(rising, 255, 0),
+            2 => Color::Rgb(0, 255, rising),
+            3 => Color::Rgb(0, rising, 255),
+            4 => Color::Rgb(rising, 0, 255),
+            _ => Color::Rgb(255, 0, rising),
+        }
+    }
+
+    /**
+     * Returns the index of a named color in the 16 color palette.
+     */
+    fn ansi_index(&self) -> u8 {
+        match *self {
+            Color::Black => 0,
+            Color::Red => 1,
+            Color::Green => 2,
+            Color::Yellow => 3,
+            Color::Blue => 4,
+            Color::Magenta => 5,
+            Color::Cyan => 6,
+            Color::White => 7,
+            Color::Fixed(index) => index,
+            Color::Rgb(..) => self.nearest_16().ansi_index(),
+        }
+    }
+
+    /**
+     * Returns the closest color from the 256 color palette, excluding the first 16 colors whose
+     * appearance varies between terminals.
+     */
+    fn nearest_256(&self) -> Color {
+        let index = (16..=255).min_by_key(|index| distance(self.to_rgb(), Color::Fixed(*index).to_rgb())).unwrap_or(16);
+        Color::Fixed(index)
+    }
+
+    /**
+     * Returns the closest of the 16 standard and bright colors.
+     */
+    fn nearest_16(&self) -> Color {
+        let index = (0..16).min_by_key(|index| distance(self.to_rgb(), ANSI_16_RGB[*index as usize])).unwrap_or(0);
+        Color::from_ansi_index(index)
+    }
+
+    /**
+     * Returns the SGR parameters that set this color as the foreground color.
+     */
+    pub fn fg_code(&self) -> String {
+        self.code(30, 90, 38)
+    }
+
+    /**
+     * Returns the SGR parameters that set this color as the background color.
+     */
+    pub fn bg_code(&self) -> String {
+        self.code(40, 100, 48)
+    }
+
+    /**
+     * Returns the SGR parameters for this color given the base codes for the standard,
+     * bright and extended color ranges.
+     */
+    fn code(&self, standard: u8, bright: u8, extended: u8) -> String {
+        match *self {
+            Color::Black => standard.to_string(),
+            Color::Red => (standard + 1).to_string(),
+            Color::Green => (standard + 2).to_string(),
+            Color::Yellow => (standard + 3).to_string(),
+            Color::Blue => (standard + 4).to_string(),
+            Color::Magenta => (standard + 5).to_string(),
+            Color::Cyan => (standard + 6).to_string(),
+            Color::White => (standard + 7).to_string(),
+            Color::Fixed(index) if index < 8 => (standard + index).to_string(),
+            Color::Fixed(index) if index < 16 => (bright + index - 8).to_string(),
+            Color::Fixed(index) => format!("{};5;{}", extended, index),
+            Color::Rgb(r, g, b) => format!("{};2;{};{};{}", extended, r, g, b),
+        }
+    }
+}
+
+/// Returns the squared distance between two RGB colors.
+fn distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let channel = |a: u8, b: u8| (a as i32 - b as i32).pow(2) as u32;
+    channel(a.0, b.0) + channel(a.1, b.1) + channel(a.2, b.2)
+}
+
+impl fmt::Display for Color {
+    /**
+     * Formats a Color as its name, palette index or hex code.
      */
-    pub fn to_string(&self) -> &str {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            Color::Red => "red",
-            Color::Blue => "blue",
-            Color::Green => "green",
-            Color::Yellow => "yellow",
-            Color::Magenta => "magenta",
-            Color::Cyan => "cyan",
-            Color::White => "white",
-            Color::Black => "black",
+            Color::Red => write!(f, "red"),
+            Color::Blue => write!(f, "blue"),
+            Color::Green => write!(f, "green"),
+            Color::Yellow => write!(f, "yellow"),
+            Color::Magenta => write!(f, "magenta"),
+            Color::Cyan => write!(f, "cyan"),
+            Color::White => write!(f, "white"),
+            Color::Black => write!(f, "black"),
+            Color::Fixed(index) => write!(f, "color({})", index),
+            Color::Rgb(r, g, b) => write!(f, "#{:02x}{:02x}{:02x}", r, g, b),
         }
     }
 }
@@ -41,5 +237,54 @@ mod tests {
         assert_eq!(Color::Cyan.to_string(), "cyan");
         assert_eq!(Color::White.to_string(), "white");
         assert_eq!(Color::Black.to_string(), "black");
+        assert_eq!(Color::Fixed(208).to_string(), "color(208)");
+        assert_eq!(Color::Rgb(255, 128, 0).to_string(), "#ff8000");
+    }
+
+    #[test]
+    fn test_colors_from_name() {
+        assert_eq!(Color::from_name("green"), Some(Color::Green));
+        assert_eq!(Color::from_name("black"), Some(Color::Black));
+        assert_eq!(Color::from_name("purple"), None);
+    }
+
+    #[test]
+    fn test_colors_sgr_codes() {
+        assert_eq!(Color::Red.fg_code(), "31");
+        assert_eq!(Color::White.bg_code(), "47");
+        assert_eq!(Color::Fixed(9).fg_code(), "91");
+        assert_eq!(Color::Fixed(208).fg_code(), "38;5;208");
+        assert_eq!(Color::Rgb(1, 2, 3).bg_code(), "48;2;1;2;3");
+    }
+
+    /// Verifies that colors are degraded to the closest color the terminal supports.
+    #[test]
+    fn test_colors_degrade() {
+        let orange = Color::Rgb(255, 135, 0);
+        assert_eq!(orange, orange.degrade(ColorSupport::TrueColor));
+        assert_eq!(Color::Fixed(208), orange.degrade(ColorSupport::Ansi256));
+        assert_eq!(Color::Yellow, orange.degrade(ColorSupport::Ansi16));
+        assert_eq!(Color::Red, Color::Rgb(200, 10, 0).degrade(ColorSupport::Ansi16));
+        assert_eq!(Color::Fixed(15), Color::Fixed(231).degrade(ColorSupport::Ansi16));
+        assert_eq!(Color::Red, Color::Red.degrade(ColorSupport::Ansi16));
+    }
+
+    /// Verifies converting hues to colors.
+    #[test]
+    fn test_colors_from_hue() {
+        assert_eq!(Color::Rgb(255, 0, 0), Color::from_hue(0.0));
+        assert_eq!(Color::Rgb(255, 128, 0), Color::from_hue(30.0));
+        assert_eq!(Color::Rgb(0, 255, 255), Color::from_hue(180.0));
+        assert_eq!(Color::Rgb(255, 0, 0), Color::from_hue(360.0));
+    }
+
+    /// Verifies mixing two colors.
+    #[test]
+    fn test_colors_mix() {
+        let black = Color::Rgb(0, 0, 0);
+        let white = Color::Rgb(255, 255, 255);
+        assert_eq!(Color::Rgb(128, 128, 128), black.mix(white, 0.5));
+        assert_eq!(black, black.mix(white, 0.0));
+        assert_eq!(Color::Rgb(0, 0, 238), Color::Blue.mix(white, 0.0));
     }
 }
@@ -0,0 +1,13 @@
+/// Describes the range of colors a terminal can display.
+///
+/// Ordered from the fewest colors to the most, so a color can be kept when the terminal
+/// supports at least the range it comes from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ColorSupport {
+    /// The 8 standard colors and their bright variants.
+    Ansi16,
+    /// The 256 color palette.
+    Ansi256,
+    /// Truecolor (24-bit) RGB colors.
+    TrueColor,
+}
@@ -1,4 +1,6 @@
-use super::Color;
+use super::{Color, LineScale, Numbering, TextEffect, TextTransform};
+use crate::figlet::FigletFont;
+use crate::text::transform;
 
 const DEFAULT_UNDERLINE_CHAR: char = '~';
 
@@ -8,6 +10,20 @@ pub struct ElementStyle {
     pub is_underlined: bool,
     pub underline_char: char,
     pub underline_color: Color,
+    /// Colors the content one grapheme at a time, in place of the content color.
+    pub effect: Option<TextEffect>,
+    /// Shifts the colors of the effect along the content. Advance it between frames to animate.
+    pub effect_phase: f32,
+    /// Renders the content as large letters in a FIGlet font, spanning several rows.
+    pub font: Option<FigletFont>,
+    /// Draws the content at double width or double height on terminals that support it.
+    pub line_scale: LineScale,
+    /// Changes the letters of the content when it is rendered (e.g. to uppercase).
+    pub transform: TextTransform,
+    /// The number of spaces inserted between the letters of the content when it is rendered.
+    pub letter_spacing: u8,
+    /// How headers using this style are numbered, e.g. `1.`, `1.1` or `i.`.
+    pub numbering: Numbering,
 }
 
 impl ElementStyle {
@@ -18,6 +34,29 @@ impl ElementStyle {
             is_underlined: false,
             underline_char: DEFAULT_UNDERLINE_CHAR,
             underline_color: Color::White,
+            effect: None,
+            effect_phase: 0.0,
+            font: None,
+            line_scale: LineScale::Normal,
+            transform: TextTransform::None,
+            letter_spacing: 0,
+            numbering: Numbering::None,
         }
     }
+
+    /// Returns content as it is rendered, with the text transform and letter spacing applied.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The element style.
+    /// * `text` - The content.
+    pub(crate) fn apply_transforms(self: &Self, text: &str) -> String {
+        transform::space_letters(&transform::transform(text, self.transform), self.letter_spacing)
+    }
+}
+
+impl Default for ElementStyle {
+    fn default() -> Self {
+        ElementStyle::new()
+    }
 }
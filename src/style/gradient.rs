@@ -0,0 +1,109 @@
+use super::{Color, GradientDirection};
+
+/// Describes a smooth blend between two or more colors across an area.
+///
+/// Colors are interpolated in RGB between evenly spaced stops, one color per cell.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Gradient {
+    pub direction: GradientDirection,
+    pub stops: Vec<Color>,
+}
+
+impl Gradient {
+    /// Creates a new gradient.
+    ///
+    /// # Arguments
+    ///
+    /// * `direction` - The direction the colors change in.
+    /// * `stops` - The colors to blend between, in order.
+    pub fn new(direction: GradientDirection, stops: &[Color]) -> Gradient {
+        Gradient {
+            direction,
+            stops: stops.to_vec(),
+        }
+    }
+
+    /// Returns the color at a position along the gradient, where 0 is the first stop and 1 is
+    /// the last.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The gradient to sample.
+    /// * `position` - The position along the gradient.
+    pub fn color_at(self: &Self, position: f32) -> Option<Color> {
+        blend(&self.stops, position)
+    }
+
+    /// Returns the color of a cell within an area covered by the gradient.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The gradient to sample.
+    /// * `x` - The column of the cell.
+    /// * `y` - The row of the cell.
+    /// * `width` - The number of columns in the area.
+    /// * `height` - The number of rows in the area.
+    pub fn color_in(self: &Self, x: usize, y: usize, width: usize, height: usize) -> Option<Color> {
+        let fraction = |value: usize, size: usize| if size > 1 { value as f32 / (size - 1) as f32 } else { 0.0 };
+        let position = match self.direction {
+            GradientDirection::Horizontal => fraction(x, width),
+            GradientDirection::Vertical => fraction(y, height),
+            GradientDirection::Diagonal => fraction(x + y, (width + height).saturating_sub(1)),
+        };
+        self.color_at(position)
+    }
+}
+
+/// Returns the color at a position along evenly spaced stops, where 0 is the first stop and 1
+/// is the last. Returns None if there are no stops.
+///
+/// # Arguments
+///
+/// * `stops` - The colors to blend between, in order.
+/// * `position` - The position along the stops.
+pub(crate) fn blend(stops: &[Color], position: f32) -> Option<Color> {
+    let last = stops.len().checked_sub(1)?;
+    let scaled = position.clamp(0.0, 1.0) * last as f32;
+    let index = (scaled.floor() as usize).min(last.saturating_sub(1));
+    match stops.get(index + 1) {
+        Some(next) => Some(stops[index].mix(*next, scaled - index as f32)),
+        None => Some(stops[index]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verifies sampling a gradient with several stops.
+    #[test]
+    fn test_color_at() {
+        let red = Color::Rgb(255, 0, 0);
+        let blue = Color::Rgb(0, 0, 255);
+        let white = Color::Rgb(255, 255, 255);
+        let gradient = Gradient::new(GradientDirection::Horizontal, &[red, blue, white]);
+
+        assert_eq!(Some(red), gradient.color_at(0.0));
+        assert_eq!(Some(Color::Rgb(128, 0, 128)), gradient.color_at(0.25));
+        assert_eq!(Some(blue), gradient.color_at(0.5));
+        assert_eq!(Some(white), gradient.color_at(1.0));
+        assert_eq!(None, Gradient::new(GradientDirection::Vertical, &[]).color_at(0.5));
+    }
+
+    /// Verifies that each direction spreads the gradient across the matching axis.
+    #[test]
+    fn test_color_in() {
+        let black = Color::Rgb(0, 0, 0);
+        let white = Color::Rgb(255, 255, 255);
+        let horizontal = Gradient::new(GradientDirection::Horizontal, &[black, white]);
+        let vertical = Gradient::new(GradientDirection::Vertical, &[black, white]);
+        let diagonal = Gradient::new(GradientDirection::Diagonal, &[black, white]);
+
+        assert_eq!(Some(white), horizontal.color_in(4, 0, 5, 3));
+        assert_eq!(Some(black), vertical.color_in(4, 0, 5, 3));
+        assert_eq!(Some(white), vertical.color_in(0, 2, 5, 3));
+        assert_eq!(Some(Color::Rgb(128, 128, 128)), diagonal.color_in(1, 2, 4, 4));
+        assert_eq!(Some(black), horizontal.color_in(0, 0, 1, 1));
+        assert_eq!(Some(black), diagonal.color_in(0, 0, 0, 0));
+    }
+}
@@ -0,0 +1,10 @@
+/// Describes the direction colors change in across a gradient.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GradientDirection {
+    /// From the left edge to the right edge.
+    Horizontal,
+    /// From the top edge to the bottom edge.
+    Vertical,
+    /// From the top left corner to the bottom right corner.
+    Diagonal,
+}
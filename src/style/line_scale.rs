@@ -0,0 +1,10 @@
+/// Describes the size text is drawn at on terminals that support the DEC line size sequences.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LineScale {
+    /// Text is drawn at its normal size.
+    Normal,
+    /// Text is drawn twice as wide, on one row.
+    DoubleWidth,
+    /// Text is drawn twice as wide and twice as tall, across two rows.
+    DoubleHeight,
+}
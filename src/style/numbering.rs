@@ -0,0 +1,89 @@
+/// Describes how headers of a level are numbered.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Numbering {
+    /// Headers are not numbered.
+    None,
+    /// Numbered 1, 2, 3.
+    Decimal,
+    /// Numbered a, b, c, continuing with aa, ab after z.
+    LowerAlpha,
+    /// Numbered A, B, C, continuing with AA, AB after Z.
+    UpperAlpha,
+    /// Numbered i, ii, iii.
+    LowerRoman,
+    /// Numbered I, II, III.
+    UpperRoman,
+}
+
+/// The roman numerals and their values, including the subtractive pairs, from largest to smallest.
+const ROMAN_NUMERALS: [(usize, &str); 13] = [
+    (1000, "M"), (900, "CM"), (500, "D"), (400, "CD"), (100, "C"), (90, "XC"),
+    (50, "L"), (40, "XL"), (10, "X"), (9, "IX"), (5, "V"), (4, "IV"), (1, "I"),
+];
+
+impl Numbering {
+    /// Returns a number written in this style.
+    ///
+    /// Roman numerals are only written up to 3999; larger numbers are written in decimal.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The numbering style.
+    /// * `number` - The number to write, starting from 1.
+    pub fn format(self: &Self, number: usize) -> String {
+        match self {
+            Numbering::None => String::new(),
+            Numbering::Decimal => number.to_string(),
+            Numbering::LowerAlpha => alpha(number).to_lowercase(),
+            Numbering::UpperAlpha => alpha(number),
+            Numbering::LowerRoman => roman(number).to_lowercase(),
+            Numbering::UpperRoman => roman(number),
+        }
+    }
+}
+
+/// Writes a number in uppercase letters, as A to Z followed by AA to ZZ and so on.
+fn alpha(mut number: usize) -> String {
+    let mut letters = Vec::new();
+    while number > 0 {
+        number -= 1;
+        letters.push((b'A' + (number % 26) as u8) as char);
+        number /= 26;
+    }
+    letters.iter().rev().collect()
+}
+
+/// Writes a number in uppercase roman numerals, or in decimal if it has no roman form.
+fn roman(number: usize) -> String {
+    if number == 0 || number >= 4000 {
+        return number.to_string();
+    }
+
+    let mut result = String::new();
+    let mut remaining = number;
+    for (value, numeral) in ROMAN_NUMERALS.iter() {
+        while remaining >= *value {
+            result.push_str(numeral);
+            remaining -= value;
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verifies writing numbers in each style.
+    #[test]
+    fn test_format() {
+        assert_eq!("12", Numbering::Decimal.format(12));
+        assert_eq!("c", Numbering::LowerAlpha.format(3));
+        assert_eq!("Z", Numbering::UpperAlpha.format(26));
+        assert_eq!("AB", Numbering::UpperAlpha.format(28));
+        assert_eq!("xiv", Numbering::LowerRoman.format(14));
+        assert_eq!("MCMXCIV", Numbering::UpperRoman.format(1994));
+        assert_eq!("4000", Numbering::UpperRoman.format(4000));
+        assert_eq!("", Numbering::None.format(1));
+    }
+}
@@ -0,0 +1,12 @@
+/// Describes how control characters and escape sequences in content are neutralized.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SanitizePolicy {
+    /// Replaces control characters with visible escapes (e.g. `\r` becomes `\\r`).
+    Escape,
+    /// Replaces control characters with the Unicode replacement character (`�`).
+    Replace,
+    /// Removes control characters.
+    Strip,
+    /// Inserts content verbatim. Only use this for trusted content.
+    None,
+}
@@ -0,0 +1,34 @@
+use super::Color;
+
+const DEFAULT_SHADOW_GLYPH: &str = "░";
+
+/// Describes a drop shadow drawn below and to the right of a banner.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShadowStyle {
+    /// The glyph, or repeating pattern of glyphs, the shadow is drawn with (e.g. `░` or `▒`).
+    pub glyph: String,
+    /// The color of the shadow.
+    pub color: Color,
+    /// The number of columns the shadow is offset to the right.
+    pub offset_x: u8,
+    /// The number of rows the shadow is offset downwards.
+    pub offset_y: u8,
+}
+
+impl ShadowStyle {
+    /// Returns a new ShadowStyle, offset one column right and one row down.
+    pub fn new() -> ShadowStyle {
+        ShadowStyle {
+            glyph: String::from(DEFAULT_SHADOW_GLYPH),
+            color: Color::Fixed(8),
+            offset_x: 1,
+            offset_y: 1,
+        }
+    }
+}
+
+impl Default for ShadowStyle {
+    fn default() -> Self {
+        ShadowStyle::new()
+    }
+}
@@ -0,0 +1,70 @@
+use super::gradient;
+use super::Color;
+
+/// Describes an effect that colors text one grapheme at a time.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TextEffect {
+    /// Blends the text across the given colors, from the first grapheme to the last.
+    Gradient(Vec<Color>),
+    /// Cycles the text through the hues of the rainbow, once across its length.
+    Rainbow,
+}
+
+impl TextEffect {
+    /// Returns the color of a grapheme.
+    ///
+    /// The phase shifts the colors along the text, so increasing it between frames animates
+    /// the effect. A phase of 1 is a full cycle. Gradients move forwards and then back, so that
+    /// the animation has no jumps.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The effect to apply.
+    /// * `index` - The index of the grapheme within the text.
+    /// * `count` - The number of graphemes in the text.
+    /// * `phase` - The animation phase.
+    pub fn color_at(self: &Self, index: usize, count: usize, phase: f32) -> Option<Color> {
+        match self {
+            TextEffect::Gradient(stops) => {
+                let position = if count > 1 { index as f32 / (count - 1) as f32 } else { 0.0 };
+                let position = (position + 2.0 * phase).rem_euclid(2.0);
+                let position = if position > 1.0 { 2.0 - position } else { position };
+                gradient::blend(stops, position)
+            }
+            TextEffect::Rainbow => {
+                let position = index as f32 / count.max(1) as f32;
+                Some(Color::from_hue((position + phase).rem_euclid(1.0) * 360.0))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verifies that gradient effects span the text and bounce back as the phase advances.
+    #[test]
+    fn test_gradient_color_at() {
+        let black = Color::Rgb(0, 0, 0);
+        let white = Color::Rgb(255, 255, 255);
+        let effect = TextEffect::Gradient(vec![black, white]);
+
+        assert_eq!(Some(black), effect.color_at(0, 3, 0.0));
+        assert_eq!(Some(white), effect.color_at(2, 3, 0.0));
+        assert_eq!(Some(white), effect.color_at(0, 3, 0.5));
+        assert_eq!(Some(black), effect.color_at(2, 3, 0.5));
+        assert_eq!(Some(black), effect.color_at(0, 1, 0.0));
+    }
+
+    /// Verifies that rainbow effects cycle through the hues and shift with the phase.
+    #[test]
+    fn test_rainbow_color_at() {
+        let effect = TextEffect::Rainbow;
+
+        assert_eq!(Some(Color::Rgb(255, 0, 0)), effect.color_at(0, 3, 0.0));
+        assert_eq!(Some(Color::Rgb(0, 255, 0)), effect.color_at(1, 3, 0.0));
+        assert_eq!(Some(Color::Rgb(0, 0, 255)), effect.color_at(2, 3, 0.0));
+        assert_eq!(Some(Color::Rgb(0, 255, 0)), effect.color_at(0, 3, 1.0 / 3.0));
+    }
+}
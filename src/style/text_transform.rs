@@ -0,0 +1,14 @@
+/// Describes how the letters of content are changed when it is rendered.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TextTransform {
+    /// Leaves the content as it is.
+    None,
+    /// Changes every letter to uppercase.
+    Uppercase,
+    /// Changes every letter to lowercase.
+    Lowercase,
+    /// Changes the first letter of each word to uppercase and the rest to lowercase.
+    TitleCase,
+    /// Changes lowercase letters to their Unicode small capital forms (e.g. `ᴅᴇᴘʟᴏʏ`).
+    SmallCaps,
+}
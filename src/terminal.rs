@@ -0,0 +1,125 @@
+use crate::style::ColorSupport;
+
+/// The terminal width assumed when it cannot be detected.
+pub const FALLBACK_WIDTH: u16 = 80;
+
+/// Returns the width of the terminal in columns.
+///
+/// The `COLUMNS` environment variable takes precedence, followed by querying the terminal
+/// attached to stdout. Falls back to `FALLBACK_WIDTH` when neither is available.
+pub fn width() -> u16 {
+    detected_width().unwrap_or(FALLBACK_WIDTH)
+}
+
+/// Returns the width of the terminal in columns, if it can be detected.
+///
+/// The `COLUMNS` environment variable takes precedence, followed by querying the terminal
+/// attached to stdout. Returns `None` when neither is available, such as when stdout is piped.
+pub fn detected_width() -> Option<u16> {
+    columns_from_env().or_else(columns_from_tty)
+}
+
+/// Returns the range of colors the terminal can display.
+///
+/// Truecolor is detected from `COLORTERM` (`truecolor` or `24bit`), and the 256 color palette
+/// from a `TERM` ending in `256color`. Anything else is assumed to support the 16 standard
+/// colors only.
+pub fn color_support() -> ColorSupport {
+    let colorterm = std::env::var("COLORTERM").unwrap_or_default();
+    let term = std::env::var("TERM").unwrap_or_default();
+    color_support_from(&colorterm, &term)
+}
+
+/// Returns true if the terminal supports the DEC double width and double height line sequences.
+///
+/// Support is assumed for xterm, VTE based terminals (`VTE_VERSION`), iTerm2, Terminal.app
+/// and the VT terminals, but not within terminal multiplexers or terminals known to ignore the
+/// sequences. The sequences are only written with colored output, so there is no support when
+/// colored output is disabled for the process (e.g. when output is not a terminal).
+pub fn supports_line_sizes() -> bool {
+    if !colored::control::SHOULD_COLORIZE.should_colorize() {
+        return false;
+    }
+
+    let term = std::env::var("TERM").unwrap_or_default();
+    let program = std::env::var("TERM_PROGRAM").unwrap_or_default();
+    let vte = std::env::var_os("VTE_VERSION").is_some();
+    supports_line_sizes_from(&term, &program, vte)
+}
+
+/// Works out support for the DEC line size sequences from the values of `TERM`,
+/// `TERM_PROGRAM` and whether `VTE_VERSION` is set.
+fn supports_line_sizes_from(term: &str, program: &str, vte: bool) -> bool {
+    const UNSUPPORTED: [&str; 6] = ["screen", "tmux", "linux", "dumb", "kitty", "alacritty"];
+    if term.is_empty() || UNSUPPORTED.iter().any(|name| term.contains(name)) {
+        return false;
+    }
+
+    vte || program == "iTerm.app" || program == "Apple_Terminal" || term.starts_with("xterm") || term.starts_with("vt")
+}
+
+/// Works out the color support from the values of `COLORTERM` and `TERM`.
+fn color_support_from(colorterm: &str, term: &str) -> ColorSupport {
+    match colorterm.to_ascii_lowercase().as_str() {
+        "truecolor" | "24bit" => ColorSupport::TrueColor,
+        _ if term.ends_with("256color") => ColorSupport::Ansi256,
+        _ => ColorSupport::Ansi16,
+    }
+}
+
+/// Reads the terminal width from the `COLUMNS` environment variable.
+fn columns_from_env() -> Option<u16> {
+    std::env::var("COLUMNS")
+        .ok()
+        .and_then(|columns| columns.trim().parse::<u16>().ok())
+        .filter(|columns| *columns > 0)
+}
+
+/// Queries the terminal attached to stdout for its width.
+#[cfg(unix)]
+fn columns_from_tty() -> Option<u16> {
+    let mut size = libc::winsize {
+        ws_row: 0,
+        ws_col: 0,
+        ws_xpixel: 0,
+        ws_ypixel: 0,
+    };
+    // Safety: TIOCGWINSZ only writes into the provided winsize struct.
+    let result = unsafe { libc::ioctl(libc::STDOUT_FILENO, libc::TIOCGWINSZ, &mut size) };
+    if result == 0 && size.ws_col > 0 {
+        Some(size.ws_col)
+    } else {
+        None
+    }
+}
+
+/// Queries the terminal attached to stdout for its width.
+#[cfg(not(unix))]
+fn columns_from_tty() -> Option<u16> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verifies detecting color support from the environment.
+    #[test]
+    fn test_color_support_from() {
+        assert_eq!(ColorSupport::TrueColor, color_support_from("truecolor", "xterm"));
+        assert_eq!(ColorSupport::TrueColor, color_support_from("24bit", ""));
+        assert_eq!(ColorSupport::Ansi256, color_support_from("", "xterm-256color"));
+        assert_eq!(ColorSupport::Ansi16, color_support_from("", "xterm"));
+    }
+
+    /// Verifies detecting support for the DEC line size sequences from the environment.
+    #[test]
+    fn test_supports_line_sizes_from() {
+        assert!(supports_line_sizes_from("xterm-256color", "", false));
+        assert!(supports_line_sizes_from("vt220", "", false));
+        assert!(supports_line_sizes_from("gnome", "", true));
+        assert!(!supports_line_sizes_from("screen-256color", "", true));
+        assert!(!supports_line_sizes_from("xterm-kitty", "", false));
+        assert!(!supports_line_sizes_from("", "iTerm.app", false));
+    }
+}
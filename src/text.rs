@@ -0,0 +1,4 @@
+pub mod ansi;
+pub mod lines;
+pub mod sanitize;
+pub mod transform;
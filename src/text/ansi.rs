@@ -0,0 +1,210 @@
+use unicode_width::UnicodeWidthChar;
+
+/// The escape character that introduces ANSI sequences.
+pub const ESC: char = '\u{1b}';
+
+/// The SGR sequence that resets all text attributes.
+pub const RESET: &str = "\u{1b}[0m";
+
+/// Describes a piece of text split on ANSI escape sequences.
+#[derive(Debug, PartialEq)]
+pub enum Token<'a> {
+    /// Visible text.
+    Text(&'a str),
+    /// A select graphic rendition (color/attribute) sequence such as `ESC[31m`.
+    Sgr(&'a str),
+    /// Any other escape sequence (cursor movement, OSC, etc.).
+    Other(&'a str),
+}
+
+/// Splits text into visible text and escape sequences.
+///
+/// # Arguments
+///
+/// * `text` - The text to tokenize.
+pub fn tokenize(text: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut start = 0;
+    let mut pos = 0;
+
+    while pos < text.len() {
+        if !text[pos..].starts_with(ESC) {
+            pos += text[pos..].chars().next().map_or(1, |c| c.len_utf8());
+            continue;
+        }
+
+        if start < pos {
+            tokens.push(Token::Text(&text[start..pos]));
+        }
+        let end = pos + sequence_len(&text[pos..]);
+        let sequence = &text[pos..end];
+        if sequence.starts_with("\u{1b}[") && sequence.ends_with('m') {
+            tokens.push(Token::Sgr(sequence));
+        } else {
+            tokens.push(Token::Other(sequence));
+        }
+        start = end;
+        pos = end;
+    }
+    if start < text.len() {
+        tokens.push(Token::Text(&text[start..]));
+    }
+
+    tokens
+}
+
+/// Returns the length in bytes of the escape sequence at the start of the text.
+///
+/// Handles CSI (`ESC [ ... final`), OSC (`ESC ] ... BEL` or `ESC ] ... ESC \`), escapes with
+/// intermediate bytes (e.g. `ESC # 6`) and two-character escapes. Unterminated sequences run
+/// to the end of the text.
+fn sequence_len(text: &str) -> usize {
+    let bytes = text.as_bytes();
+    match bytes.get(1) {
+        Some(b'[') => bytes
+            .iter()
+            .enumerate()
+            .skip(2)
+            .find(|(_, b)| (0x40..=0x7e).contains(*b))
+            .map_or(bytes.len(), |(i, _)| i + 1),
+        Some(b']') => {
+            let mut i = 2;
+            while i < bytes.len() {
+                if bytes[i] == 0x07 {
+                    return i + 1;
+                }
+                if bytes[i] == 0x1b && bytes.get(i + 1) == Some(&b'\\') {
+                    return i + 2;
+                }
+                i += 1;
+            }
+            bytes.len()
+        }
+        Some(0x20..=0x2f) => bytes
+            .iter()
+            .enumerate()
+            .skip(2)
+            .find(|(_, b)| !(0x20..=0x2f).contains(*b))
+            .map_or(bytes.len(), |(i, b)| if (0x30..=0x7e).contains(b) { i + 1 } else { i }),
+        Some(_) => 1 + text[1..].chars().next().map_or(0, |c| c.len_utf8()),
+        None => 1,
+    }
+}
+
+/// Removes all escape sequences from the text.
+///
+/// # Arguments
+///
+/// * `text` - The text to strip.
+pub fn strip(text: &str) -> String {
+    tokenize(text)
+        .into_iter()
+        .filter_map(|token| match token {
+            Token::Text(t) => Some(t),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns the number of terminal columns a character occupies.
+///
+/// Wide characters (such as CJK and most emoji) occupy two columns, and combining marks and
+/// control characters occupy none.
+///
+/// # Arguments
+///
+/// * `c` - The character to measure.
+pub fn char_width(c: char) -> usize {
+    UnicodeWidthChar::width(c).unwrap_or(0)
+}
+
+/// Returns the number of columns the visible text occupies, ignoring escape sequences.
+///
+/// # Arguments
+///
+/// * `text` - The text to measure.
+pub fn visible_width(text: &str) -> usize {
+    tokenize(text)
+        .iter()
+        .map(|token| match token {
+            Token::Text(t) => t.chars().map(char_width).sum(),
+            _ => 0,
+        })
+        .sum()
+}
+
+/// Returns the byte index after the given number of visible columns.
+///
+/// Escape sequences before that point are included; those after it are not. A wide character
+/// that would straddle the split is left after it.
+///
+/// # Arguments
+///
+/// * `text` - The text to measure.
+/// * `visible` - The number of visible columns to skip.
+pub fn split_at_visible(text: &str, visible: usize) -> usize {
+    let mut remaining = visible;
+    let mut pos = 0;
+    for token in tokenize(text) {
+        match token {
+            Token::Text(t) => {
+                for (i, c) in t.char_indices() {
+                    let width = char_width(c);
+                    if width > remaining || (remaining == 0 && width > 0) {
+                        return pos + i;
+                    }
+                    remaining -= width;
+                }
+                pos += t.len();
+            }
+            Token::Sgr(t) | Token::Other(t) => {
+                if remaining == 0 {
+                    return pos;
+                }
+                pos += t.len();
+            }
+        }
+    }
+
+    pos
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verifies that SGR and OSC sequences do not count towards the visible width.
+    #[test]
+    fn test_visible_width_ignores_sequences() {
+        assert_eq!(5, visible_width("\u{1b}[31mHello\u{1b}[0m"));
+        assert_eq!(4, visible_width("\u{1b}]8;;http://x\u{7}link\u{1b}]8;;\u{1b}\\"));
+        assert_eq!(3, visible_width("a\u{1b}[2Kbc"));
+    }
+
+    /// Verifies that stripping removes every escape sequence.
+    #[test]
+    fn test_strip() {
+        assert_eq!("Hello world", strip("\u{1b}[1;32mHello\u{1b}[0m world"));
+        assert_eq!("plain", strip("plain"));
+        assert_eq!("wide", strip("\u{1b}#6wide"));
+        assert_eq!("é", strip("\u{1b}(é"));
+    }
+
+    /// Verifies splitting after a number of visible characters.
+    #[test]
+    fn test_split_at_visible() {
+        assert_eq!(2, split_at_visible("abcd", 2));
+        assert_eq!(7, split_at_visible("\u{1b}[31mab\u{1b}[0m", 2));
+        assert_eq!(4, split_at_visible("abcd", 10));
+        assert_eq!(0, split_at_visible("日本", 1));
+        assert_eq!(3, split_at_visible("日本", 2));
+    }
+
+    /// Verifies that wide characters count as two columns.
+    #[test]
+    fn test_visible_width_wide() {
+        assert_eq!(4, visible_width("日本"));
+        assert_eq!(3, visible_width("a🎉"));
+        assert_eq!(1, visible_width("e\u{301}"));
+    }
+}
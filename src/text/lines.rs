@@ -0,0 +1,191 @@
+use super::ansi;
+
+/// Splits text into rows on `\n` and `\r\n` line breaks.
+///
+/// Empty text produces a single empty row.
+///
+/// # Arguments
+///
+/// * `text` - The text to split.
+pub fn split_lines(text: &str) -> Vec<&str> {
+    let rows: Vec<&str> = text.lines().collect();
+    if rows.is_empty() {
+        vec![""]
+    } else {
+        rows
+    }
+}
+
+/// Replaces tab characters with spaces up to the next tab stop.
+///
+/// # Arguments
+///
+/// * `text` - A single row of text.
+/// * `tab_width` - The distance between tab stops. A width of zero removes tabs.
+pub fn expand_tabs(text: &str, tab_width: u8) -> String {
+    expand_tabs_at(text, tab_width, 0)
+}
+
+/// Replaces tab characters with spaces up to the next tab stop, for text that starts part of
+/// the way along a row.
+///
+/// # Arguments
+///
+/// * `text` - The text, without line breaks.
+/// * `tab_width` - The distance between tab stops. A width of zero removes tabs.
+/// * `column` - The column of the row the text starts at.
+pub fn expand_tabs_at(text: &str, tab_width: u8, column: usize) -> String {
+    if !text.contains('\t') {
+        return text.to_string();
+    }
+
+    let tab_width = tab_width as usize;
+    let mut result = String::with_capacity(text.len());
+    let mut column = column;
+    for token in ansi::tokenize(text) {
+        match token {
+            ansi::Token::Text(t) => {
+                for c in t.chars() {
+                    if c == '\t' {
+                        let spaces = if tab_width == 0 { 0 } else { tab_width - column % tab_width };
+                        result.push_str(&" ".repeat(spaces));
+                        column += spaces;
+                    } else {
+                        result.push(c);
+                        column += ansi::char_width(c);
+                    }
+                }
+            }
+            ansi::Token::Sgr(t) | ansi::Token::Other(t) => result.push_str(t),
+        }
+    }
+
+    result
+}
+
+/// Wraps a single row of text on whitespace so that no row exceeds the given width.
+///
+/// Words longer than the width are broken across rows. Whitespace within a row is kept as it
+/// is, including leading indentation, while whitespace where the text is wrapped and at the
+/// end of the text is dropped. Widths are measured on visible text, ignoring escape sequences.
+///
+/// # Arguments
+///
+/// * `text` - A single row of text, without tabs.
+/// * `width` - The maximum visible width of each row.
+pub fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut rows: Vec<String> = Vec::new();
+    let mut current = String::new();
+    let mut current_width = 0;
+    let mut space = "";
+
+    for piece in split_whitespace_runs(text) {
+        if piece.starts_with(char::is_whitespace) {
+            space = piece;
+            continue;
+        }
+        let mut word = piece;
+        let mut word_width = ansi::visible_width(word);
+
+        // Keep the whitespace before the word, unless the row is wrapped there
+        let indent = rows.is_empty() && current_width == 0 && ansi::visible_width(space) < width;
+        let space_width = if current_width > 0 || indent { ansi::visible_width(space) } else { 0 };
+        if current_width > 0 && current_width + space_width + word_width > width {
+            rows.push(std::mem::take(&mut current));
+            current_width = 0;
+        } else if space_width > 0 {
+            current.push_str(space);
+            current_width += space_width;
+        }
+        space = "";
+
+        while current_width + word_width > width && width > 0 {
+            let mut split = ansi::split_at_visible(word, width.saturating_sub(current_width));
+            if split == 0 {
+                // Always make progress, even if a wide character does not fit the row
+                split = word.chars().next().map_or(0, char::len_utf8);
+            }
+            current.push_str(&word[..split]);
+            rows.push(std::mem::take(&mut current));
+            current_width = 0;
+            word = &word[split..];
+            word_width = ansi::visible_width(word);
+        }
+
+        current.push_str(word);
+        current_width += word_width;
+    }
+
+    if !current.is_empty() || rows.is_empty() {
+        rows.push(current);
+    }
+
+    rows
+}
+
+/// Splits text into alternating runs of whitespace and of other characters.
+fn split_whitespace_runs(text: &str) -> Vec<&str> {
+    let mut runs = Vec::new();
+    let mut start = 0;
+    let mut previous = None;
+    for (index, c) in text.char_indices() {
+        let whitespace = c.is_whitespace();
+        if previous.is_some_and(|previous| previous != whitespace) {
+            runs.push(&text[start..index]);
+            start = index;
+        }
+        previous = Some(whitespace);
+    }
+    if start < text.len() {
+        runs.push(&text[start..]);
+    }
+
+    runs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verifies splitting on both line break styles.
+    #[test]
+    fn test_split_lines() {
+        assert_eq!(vec!["a", "b", "c"], split_lines("a\nb\r\nc"));
+        assert_eq!(vec![""], split_lines(""));
+    }
+
+    /// Verifies that tabs advance to the next tab stop.
+    #[test]
+    fn test_expand_tabs() {
+        assert_eq!("a   b", expand_tabs("a\tb", 4));
+        assert_eq!("abcd    e", expand_tabs("abcd\te", 4));
+        assert_eq!("\u{1b}[31ma\u{1b}[0m b", expand_tabs("\u{1b}[31ma\u{1b}[0m\tb", 2));
+        assert_eq!("ab", expand_tabs("a\tb", 0));
+        assert_eq!("日本  b", expand_tabs("日本\tb", 3));
+        assert_eq!("a  b", expand_tabs_at("a\tb", 4, 5));
+    }
+
+    /// Verifies wrapping on whitespace and breaking of long words.
+    #[test]
+    fn test_wrap() {
+        assert_eq!(vec!["one two", "three"], wrap("one two three", 8));
+        assert_eq!(vec!["abcd", "efgh", "ij"], wrap("abcdefghij", 4));
+        assert_eq!(vec![""], wrap("", 4));
+    }
+
+    /// Verifies that whitespace within rows is kept and whitespace at wraps is dropped.
+    #[test]
+    fn test_wrap_keeps_whitespace() {
+        assert_eq!(vec!["a  b   c"], wrap("a  b   c", 10));
+        assert_eq!(vec!["a  b", "c"], wrap("a  b   c  ", 4));
+        assert_eq!(vec!["  ab", "cd"], wrap("  ab cd", 4));
+        assert_eq!(vec!["abcd"], wrap("      abcd", 4));
+    }
+
+    /// Verifies that escape sequences do not count towards the width.
+    #[test]
+    fn test_wrap_escapes() {
+        let red = "\u{1b}[31mred\u{1b}[0m";
+        assert_eq!(vec![format!("{} ok", red)], wrap(&format!("{} ok", red), 6));
+    }
+}
@@ -0,0 +1,94 @@
+use super::super::style::SanitizePolicy;
+use super::ansi::{self, Token};
+
+/// The character used to replace unsafe characters under `SanitizePolicy::Replace`.
+const REPLACEMENT_CHAR: char = '\u{fffd}';
+
+/// Neutralizes control characters and escape sequences so content cannot corrupt a banner
+/// or rewrite the terminal.
+///
+/// # Arguments
+///
+/// * `text` - The untrusted text to sanitize.
+/// * `policy` - How unsafe characters are neutralized.
+/// * `keep_sgr` - True to keep color (SGR) sequences, which only change text attributes.
+pub fn sanitize(text: &str, policy: SanitizePolicy, keep_sgr: bool) -> String {
+    if policy == SanitizePolicy::None {
+        return text.to_string();
+    }
+
+    let mut result = String::with_capacity(text.len());
+    for token in ansi::tokenize(text) {
+        match token {
+            Token::Sgr(sgr) if keep_sgr => result.push_str(sgr),
+            Token::Sgr(t) | Token::Other(t) | Token::Text(t) => {
+                for c in t.chars() {
+                    push_char(&mut result, c, policy);
+                }
+            }
+        }
+    }
+
+    result
+}
+
+/// Appends a character to the result, neutralizing it if it is unsafe.
+fn push_char(result: &mut String, c: char, policy: SanitizePolicy) {
+    if !is_unsafe(c) {
+        result.push(c);
+        return;
+    }
+
+    match policy {
+        SanitizePolicy::Escape => result.extend(c.escape_default()),
+        SanitizePolicy::Replace => result.push(REPLACEMENT_CHAR),
+        SanitizePolicy::Strip | SanitizePolicy::None => {}
+    }
+}
+
+/// Returns true for characters that can move the cursor, alter the terminal or reorder text.
+///
+/// Covers C0 and C1 control characters, DEL and the Unicode bidirectional overrides.
+fn is_unsafe(c: char) -> bool {
+    c.is_control() || ('\u{202a}'..='\u{202e}').contains(&c) || ('\u{2066}'..='\u{2069}').contains(&c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verifies that carriage returns and newlines cannot overwrite or break a row.
+    #[test]
+    fn test_escape_line_breaks() {
+        assert_eq!(
+            "ok\\rFAKE\\n",
+            sanitize("ok\rFAKE\n", SanitizePolicy::Escape, true)
+        );
+    }
+
+    /// Verifies that cursor movement and OSC sequences are neutralized while colors are kept.
+    #[test]
+    fn test_escape_sequences() {
+        let input = "\u{1b}[32mok\u{1b}[0m\u{1b}[2J\u{1b}]0;pwned\u{7}";
+        assert_eq!(
+            "\u{1b}[32mok\u{1b}[0m\\u{1b}[2J\\u{1b}]0;pwned\\u{7}",
+            sanitize(input, SanitizePolicy::Escape, true)
+        );
+        assert_eq!("\\u{1b}[32mok", sanitize("\u{1b}[32mok", SanitizePolicy::Escape, false));
+    }
+
+    /// Verifies the replace and strip policies, including C1 controls and bidi overrides.
+    #[test]
+    fn test_replace_and_strip() {
+        let input = "a\u{9b}2Jb\u{202e}c\u{7f}";
+        assert_eq!("a\u{fffd}2Jb\u{fffd}c\u{fffd}", sanitize(input, SanitizePolicy::Replace, true));
+        assert_eq!("a2Jbc", sanitize(input, SanitizePolicy::Strip, true));
+        assert_eq!("a[2Jb", sanitize("a\u{1b}[2Jb", SanitizePolicy::Strip, true));
+    }
+
+    /// Verifies that the none policy leaves content untouched.
+    #[test]
+    fn test_none() {
+        assert_eq!("a\rb", sanitize("a\rb", SanitizePolicy::None, true));
+    }
+}
@@ -0,0 +1,118 @@
+use super::super::style::TextTransform;
+use super::ansi::{self, Token};
+use unicode_segmentation::UnicodeSegmentation;
+
+/// The Unicode small capital forms of the letters `a` to `z`. There is no small capital `x`,
+/// so it is left as it is.
+const SMALL_CAPS: [char; 26] = [
+    'ᴀ', 'ʙ', 'ᴄ', 'ᴅ', 'ᴇ', 'ꜰ', 'ɢ', 'ʜ', 'ɪ', 'ᴊ', 'ᴋ', 'ʟ', 'ᴍ',
+    'ɴ', 'ᴏ', 'ᴘ', 'ꞯ', 'ʀ', 'ꜱ', 'ᴛ', 'ᴜ', 'ᴠ', 'ᴡ', 'x', 'ʏ', 'ᴢ',
+];
+
+/// Changes the letters of text as the transform describes, leaving escape sequences untouched.
+///
+/// # Arguments
+///
+/// * `text` - The text to transform.
+/// * `transform` - How the letters are changed.
+pub fn transform(text: &str, transform: TextTransform) -> String {
+    if transform == TextTransform::None {
+        return text.to_string();
+    }
+
+    let mut result = String::with_capacity(text.len());
+    let mut at_word_start = true;
+    for token in ansi::tokenize(text) {
+        match token {
+            Token::Text(t) => {
+                for c in t.chars() {
+                    match transform {
+                        TextTransform::Uppercase => result.extend(c.to_uppercase()),
+                        TextTransform::Lowercase => result.extend(c.to_lowercase()),
+                        TextTransform::TitleCase if at_word_start => result.extend(c.to_uppercase()),
+                        TextTransform::TitleCase => result.extend(c.to_lowercase()),
+                        TextTransform::SmallCaps => result.push(small_cap(c)),
+                        TextTransform::None => result.push(c),
+                    }
+                    at_word_start = c.is_whitespace();
+                }
+            }
+            Token::Sgr(t) | Token::Other(t) => result.push_str(t),
+        }
+    }
+
+    result
+}
+
+/// Inserts spaces between the letters of text (e.g. `D E P L O Y`), leaving escape sequences
+/// untouched. Letters are whole graphemes, so combining marks stay with their letter.
+///
+/// # Arguments
+///
+/// * `text` - The text to space out.
+/// * `spacing` - The number of spaces inserted between letters.
+pub fn space_letters(text: &str, spacing: u8) -> String {
+    if spacing == 0 {
+        return text.to_string();
+    }
+
+    let spaces = " ".repeat(spacing as usize);
+    let mut result = String::with_capacity(text.len() * (1 + spacing as usize));
+    let mut first = true;
+    for token in ansi::tokenize(text) {
+        match token {
+            Token::Text(t) => {
+                for grapheme in t.graphemes(true) {
+                    if !first {
+                        result.push_str(&spaces);
+                    }
+                    result.push_str(grapheme);
+                    first = false;
+                }
+            }
+            Token::Sgr(t) | Token::Other(t) => result.push_str(t),
+        }
+    }
+
+    result
+}
+
+/// Returns the small capital form of a lowercase ASCII letter, or the character unchanged.
+fn small_cap(c: char) -> char {
+    if c.is_ascii_lowercase() {
+        SMALL_CAPS[(c as u8 - b'a') as usize]
+    } else {
+        c
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verifies each of the case transforms.
+    #[test]
+    fn test_transform() {
+        assert_eq!("DEPLOY ÉTÉ", transform("Deploy été", TextTransform::Uppercase));
+        assert_eq!("deploy", transform("DePLoy", TextTransform::Lowercase));
+        assert_eq!("Deploy To Prod", transform("deploy tO PROD", TextTransform::TitleCase));
+        assert_eq!("Dᴇᴘʟᴏʏ 42", transform("Deploy 42", TextTransform::SmallCaps));
+        assert_eq!("Deploy", transform("Deploy", TextTransform::None));
+    }
+
+    /// Verifies that escape sequences are kept as they are.
+    #[test]
+    fn test_transform_keeps_escapes() {
+        assert_eq!("\u{1b}[31mAB\u{1b}[0mC", transform("\u{1b}[31mab\u{1b}[0mc", TextTransform::Uppercase));
+        assert_eq!("A\u{1b}[1mb C", transform("a\u{1b}[1mB c", TextTransform::TitleCase));
+    }
+
+    /// Verifies spacing out letters, keeping graphemes and escape sequences whole.
+    #[test]
+    fn test_space_letters() {
+        assert_eq!("D E P L O Y", space_letters("DEPLOY", 1));
+        assert_eq!("a  e\u{301}", space_letters("ae\u{301}", 2));
+        assert_eq!("\u{1b}[31ma b\u{1b}[0m c", space_letters("\u{1b}[31mab\u{1b}[0mc", 1));
+        assert_eq!("ab", space_letters("ab", 0));
+    }
+}